@@ -0,0 +1,47 @@
+#![feature(test)]
+
+extern crate test;
+
+use core::net;
+
+use ip_cidr::{Cidr, CidrSet};
+
+const BLOCKS: u32 = 10_000;
+
+//Distinct /24 blocks spread across address space
+fn blocks() -> Vec<Cidr> {
+    (0..BLOCKS).map(|idx| {
+        let addr = net::Ipv4Addr::from_bits(idx.wrapping_mul(0x9e37_79b9) & 0xffff_ff00);
+        Cidr::new_v4(addr, 24).expect("to create")
+    }).collect()
+}
+
+//Mix of addresses within the last blocks and addresses not within any block
+fn addrs() -> Vec<net::IpAddr> {
+    (0..64u32).map(|idx| {
+        let idx = BLOCKS - 1 - idx;
+        let bits = idx.wrapping_mul(0x9e37_79b9) & 0xffff_ff00;
+        match idx % 2 {
+            0 => net::IpAddr::V4(net::Ipv4Addr::from_bits(bits | 1)),
+            _ => net::IpAddr::V4(net::Ipv4Addr::from_bits(bits ^ 0x8000_0000)),
+        }
+    }).collect()
+}
+
+#[bench]
+fn linear_scan(bencher: &mut test::Bencher) {
+    let blocks = blocks();
+    let addrs = addrs();
+    bencher.iter(|| {
+        addrs.iter().filter(|addr| blocks.iter().any(|block| block.contains(**addr))).count()
+    });
+}
+
+#[bench]
+fn cidr_set(bencher: &mut test::Bencher) {
+    let set = blocks().into_iter().collect::<CidrSet>();
+    let addrs = addrs();
+    bencher.iter(|| {
+        addrs.iter().filter(|addr| set.contains(**addr)).count()
+    });
+}
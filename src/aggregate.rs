@@ -0,0 +1,97 @@
+use core::net;
+
+use crate::Cidr;
+
+#[inline(always)]
+const fn network_bits(cidr: &Cidr) -> u128 {
+    match cidr {
+        Cidr::V4(cidr) => cidr.network_addr().to_bits() as _,
+        Cidr::V6(cidr) => cidr.network_addr().to_bits(),
+    }
+}
+
+#[inline(always)]
+const fn bits_len(cidr: &Cidr) -> u8 {
+    match cidr {
+        Cidr::V4(_) => Cidr::IPV4_BITS,
+        Cidr::V6(_) => Cidr::IPV6_BITS,
+    }
+}
+
+#[inline(always)]
+const fn is_same_family(left: &Cidr, right: &Cidr) -> bool {
+    matches!((left, right), (Cidr::V4(_), Cidr::V4(_)) | (Cidr::V6(_), Cidr::V6(_)))
+}
+
+//`prefix` must not exceed address length
+#[inline(always)]
+fn block(addr: net::IpAddr, prefix: u8) -> Cidr {
+    match Cidr::new(addr, prefix) {
+        Some(cidr) => cidr,
+        None => unreachable!(),
+    }
+}
+
+//Both `low` and `high` are canonical and sorted
+#[inline]
+fn merge_siblings(low: &Cidr, high: &Cidr) -> Option<Cidr> {
+    let prefix = low.prefix();
+    if !is_same_family(low, high) || prefix != high.prefix() || prefix == 0 {
+        return None;
+    }
+
+    let sibling_bit = 1u128 << (bits_len(low) - prefix);
+    let low_bits = network_bits(low);
+    if low_bits & sibling_bit == 0 && low_bits | sibling_bit == network_bits(high) {
+        Some(block(low.addr(), prefix - 1))
+    } else {
+        None
+    }
+}
+
+///Merges adjacent and overlapping `blocks` in place into the smallest equivalent set of blocks
+///
+///Merged blocks are moved to the front of `blocks`, and the function returns their number.
+///Content of `blocks` past returned length is unspecified.
+///
+///Merged blocks have host bits cleared and are sorted with IPv4 blocks first, then by network address.
+pub fn aggregate_into(blocks: &mut [Cidr]) -> usize {
+    for block in blocks.iter_mut() {
+        *block = block.canonical();
+    }
+    blocks.sort_unstable_by_key(Cidr::sort_key);
+
+    let mut len = 0;
+    for idx in 0..blocks.len() {
+        let block = blocks[idx];
+        if len > 0 && blocks[len - 1].contains_cidr(&block) {
+            continue;
+        }
+
+        blocks[len] = block;
+        len += 1;
+
+        while len >= 2 {
+            match merge_siblings(&blocks[len - 2], &blocks[len - 1]) {
+                Some(merged) => {
+                    len -= 1;
+                    blocks[len - 1] = merged;
+                },
+                None => break,
+            }
+        }
+    }
+
+    len
+}
+
+#[cfg(feature = "alloc")]
+///Merges adjacent and overlapping `blocks` into the smallest equivalent set of blocks
+///
+///Returned blocks have host bits cleared and are sorted with IPv4 blocks first, then by network address.
+pub fn aggregate(blocks: &[Cidr]) -> alloc::vec::Vec<Cidr> {
+    let mut result = blocks.to_vec();
+    let len = aggregate_into(&mut result);
+    result.truncate(len);
+    result
+}
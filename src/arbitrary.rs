@@ -0,0 +1,56 @@
+use core::net;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{v4, v6, Cidr};
+
+impl<'a> Arbitrary<'a> for v4::Cidr {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let addr = net::Ipv4Addr::from_bits(u.arbitrary()?);
+        let prefix = u.int_in_range(0..=v4::BITS_LEN)?;
+        match Self::new(addr, prefix) {
+            Some(cidr) => Ok(cidr),
+            None => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(u32::size_hint(depth), u8::size_hint(depth))
+    }
+}
+
+impl<'a> Arbitrary<'a> for v6::Cidr {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let addr = net::Ipv6Addr::from_bits(u.arbitrary()?);
+        let prefix = u.int_in_range(0..=v6::BITS_LEN)?;
+        match Self::new(addr, prefix) {
+            Some(cidr) => Ok(cidr),
+            None => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(u128::size_hint(depth), u8::size_hint(depth))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Cidr {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u.arbitrary()? {
+            true => Ok(Self::V6(u.arbitrary()?)),
+            false => Ok(Self::V4(u.arbitrary()?)),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let v4 = v4::Cidr::size_hint(depth);
+        let v6 = v6::Cidr::size_hint(depth);
+        arbitrary::size_hint::and(bool::size_hint(depth), arbitrary::size_hint::or(v4, v6))
+    }
+}
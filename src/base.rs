@@ -6,10 +6,44 @@ use core::fmt;
 pub trait NetworkAddress: Clone + Copy + fmt::Debug + fmt::Display + PartialEq + Eq + PartialOrd + Ord {
     ///Max possible length of the address in bits
     const BITS_LEN: u8;
+
+    ///Returns network mask with `prefix` leading bits set, assuming `prefix` is not greater than `BITS_LEN`
+    fn mask(prefix: u8) -> Self;
+
+    #[inline(always)]
+    ///Returns address with host bits cleared according to `prefix`, used by alternate form of `Display` (`{:#}`)
+    ///
+    ///Default implementation returns address unchanged, as host bits cannot be cleared without knowing address representation
+    fn network(self, _prefix: u8) -> Self {
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Error indicating that prefix doesn't fit the address
+pub struct PrefixError {
+    ///Provided prefix
+    pub prefix: u8,
+    ///Maximum prefix of the address family
+    pub max: u8,
+}
+
+impl fmt::Display for PrefixError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { prefix, max } = self;
+        fmt.write_fmt(format_args!("Prefix '{prefix}' is greater than {max}"))
+    }
+}
+
+impl core::error::Error for PrefixError {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-///CIDR representation of IPv4 network
+///CIDR representation of IP network
+///
+///Blocks are ordered by prefix, then by stored address.
+///To order blocks by network address use [Cidr::sort_key](../enum.Cidr.html#method.sort_key) instead.
 pub struct Cidr<A> {
     prefix: u8,
     addr: A,
@@ -31,6 +65,31 @@ impl<A: NetworkAddress> Cidr<A> {
         }
     }
 
+    #[inline]
+    ///Constructs new CIDR verifying that `prefix` fits provided `addrs`
+    ///
+    ///Returns `Err` describing the maximum allowed prefix if `prefix` is greater than address length
+    pub const fn try_new(addr: A, prefix: u8) -> Result<Self, PrefixError> {
+        match Self::new(addr, prefix) {
+            Some(cidr) => Ok(cidr),
+            None => Err(PrefixError {
+                prefix,
+                max: A::BITS_LEN,
+            }),
+        }
+    }
+
+    #[inline]
+    ///Constructs new CIDR from number of host bits, i.e. with prefix `BITS_LEN - host_bits`
+    ///
+    ///Returns `None` if `host_bits` is greater than address length
+    pub const fn from_host_bits(addr: A, host_bits: u8) -> Option<Self> {
+        match A::BITS_LEN.checked_sub(host_bits) {
+            Some(prefix) => Self::new(addr, prefix),
+            None => None,
+        }
+    }
+
     #[inline]
     ///Constructs new CIDR with single `addr`
     pub const fn new_single(addr: A) -> Self {
@@ -51,18 +110,197 @@ impl<A: NetworkAddress> Cidr<A> {
     pub const fn prefix(&self) -> u8 {
         self.prefix
     }
+
+    #[inline(always)]
+    ///Returns network mask of the block
+    ///
+    ///Same as `netmask` of IPv4 and IPv6 blocks, but available for any `A`
+    pub fn mask(&self) -> A {
+        A::mask(self.prefix)
+    }
 }
 
-impl<A: NetworkAddress> fmt::Display for Cidr<A> {
+#[inline]
+//Returns size hint of iterator with `span + 1` remaining items, without upper bound if it does not fit `usize`
+pub(crate) const fn span_size_hint(span: u128) -> (usize, Option<usize>) {
+    if span < usize::MAX as u128 {
+        let len = span as usize + 1;
+        (len, Some(len))
+    } else {
+        (usize::MAX, None)
+    }
+}
+
+#[derive(Clone, Debug)]
+///Iterator over addresses of the [Cidr](struct.Cidr.html) block, from network address to broadcast address
+///
+///Number of remaining addresses is exact only when it fits `usize`, otherwise `len()` is `usize::MAX`
+///and `size_hint()` has no upper bound.
+pub struct CidrIter<A> {
+    pub(crate) front: A,
+    pub(crate) back: A,
+    pub(crate) exhausted: bool,
+}
+
+#[derive(Clone, Debug)]
+///Iterator over subnets of the [Cidr](struct.Cidr.html) block with the same prefix, in ascending order
+///
+///Number of remaining subnets is exact only when it fits `usize`, otherwise `len()` is `usize::MAX`
+///and `size_hint()` has no upper bound.
+pub struct SubnetIter<A> {
+    pub(crate) front: A,
+    pub(crate) back: A,
+    pub(crate) prefix: u8,
+    pub(crate) exhausted: bool,
+}
+
+impl<A: NetworkAddress> TryFrom<(A, u8)> for Cidr<A> {
+    type Error = PrefixError;
+
+    #[inline(always)]
+    fn try_from((addr, prefix): (A, u8)) -> Result<Self, Self::Error> {
+        Self::try_new(addr, prefix)
+    }
+}
+
+///Conversion of the network address to and from its bits, enabling [CidrOps](trait.CidrOps.html) for custom address types
+///
+///Bits are stored in lowest `BITS_LEN` bits of `u128`.
+pub trait AddressBits: NetworkAddress {
+    ///Returns bits of the address
+    fn to_bits(self) -> u128;
+    ///Creates address from `bits`, ignoring bits past `BITS_LEN`
+    fn from_bits(bits: u128) -> Self;
+}
+
+impl AddressBits for core::net::Ipv4Addr {
     #[inline(always)]
+    fn to_bits(self) -> u128 {
+        core::net::Ipv4Addr::to_bits(self) as _
+    }
+
+    #[inline(always)]
+    fn from_bits(bits: u128) -> Self {
+        core::net::Ipv4Addr::from_bits(bits as _)
+    }
+}
+
+impl AddressBits for core::net::Ipv6Addr {
+    #[inline(always)]
+    fn to_bits(self) -> u128 {
+        core::net::Ipv6Addr::to_bits(self)
+    }
+
+    #[inline(always)]
+    fn from_bits(bits: u128) -> Self {
+        core::net::Ipv6Addr::from_bits(bits)
+    }
+}
+
+///Block operations available for any address implementing [AddressBits](trait.AddressBits.html)
+///
+///IPv4 and IPv6 blocks provide the same operations as inherent `const` methods, which take precedence over this trait.
+///
+///Inherent `size` is limited by width of the address, hence it saturates to `u32::MAX` for IPv4 `/0`,
+///while this trait returns exact `2^32` and allows [get](#tymethod.get) of `255.255.255.255`.
+///Both agree on every other block, including IPv6 `/0` which saturates to `u128::MAX`.
+pub trait CidrOps {
+    ///Address type
+    type Addr;
+
+    ///Returns network address, which is lowest possible address within the block
+    fn network_addr(&self) -> Self::Addr;
+    ///Returns broadcast address, which is highest possible address within the block
+    fn broadcast_addr(&self) -> Self::Addr;
+    ///Returns number of addresses within the block, saturating at `u128::MAX`
+    fn size(&self) -> u128;
+    ///Checks whether `addr` is contained within the block
+    fn contains(&self, addr: Self::Addr) -> bool;
+    ///Returns address at `idx` from network address, or `None` if `idx` is not less than [size](#tymethod.size)
+    fn get(&self, idx: u128) -> Option<Self::Addr>;
+}
+
+impl<A: AddressBits> Cidr<A> {
+    #[inline(always)]
+    //Returns bits of network mask within lowest `BITS_LEN` bits
+    fn mask_bits(&self) -> u128 {
+        self.mask().to_bits()
+    }
+
+    #[inline(always)]
+    //Returns bits of all addresses within lowest `BITS_LEN` bits
+    fn addr_bits() -> u128 {
+        u128::MAX >> (u128::BITS - A::BITS_LEN as u32)
+    }
+}
+
+impl<A: AddressBits> CidrOps for Cidr<A> {
+    type Addr = A;
+
+    #[inline]
+    fn network_addr(&self) -> A {
+        A::from_bits(self.addr.to_bits() & self.mask_bits())
+    }
+
+    #[inline]
+    fn broadcast_addr(&self) -> A {
+        A::from_bits(self.addr.to_bits() | (!self.mask_bits() & Self::addr_bits()))
+    }
+
+    #[inline]
+    fn size(&self) -> u128 {
+        (!self.mask_bits() & Self::addr_bits()).saturating_add(1)
+    }
+
+    #[inline]
+    fn contains(&self, addr: A) -> bool {
+        addr.to_bits() & self.mask_bits() == self.addr.to_bits() & self.mask_bits()
+    }
+
+    #[inline]
+    fn get(&self, idx: u128) -> Option<A> {
+        if idx >= CidrOps::size(self) {
+            return None;
+        }
+
+        Some(A::from_bits(CidrOps::network_addr(self).to_bits() + idx))
+    }
+}
+
+impl<A: NetworkAddress> fmt::Display for Cidr<A> {
+    #[inline]
+    ///Writes `addr/prefix`
+    ///
+    ///Alternate form (`{:#}`) writes [network](trait.NetworkAddress.html#method.network) address instead of stored address
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { addr, prefix } = self;
+        let Self { addr, prefix } = *self;
+        let addr = match fmt.alternate() {
+            true => addr.network(prefix),
+            false => addr,
+        };
         fmt.write_fmt(format_args!("{addr}/{prefix}"))
     }
 }
 
 macro_rules! impl_base_methods {
     ($typ:ty where REPR=$repr:ident) => {
+        //Mask math relies on REPR being exactly as wide as the address
+        const _: () = assert!(
+            BITS_LEN as u32 == $repr::BITS && <$typ>::BITS == $repr::BITS && <$typ as $crate::base::NetworkAddress>::BITS_LEN == BITS_LEN,
+            "BITS_LEN must match width of the address"
+        );
+
+        #[inline(always)]
+        ///Returns whether `prefix` fits the address
+        pub const fn is_valid_prefix(prefix: u8) -> bool {
+            prefix <= BITS_LEN
+        }
+
+        ///Lowest possible address
+        pub const FIRST: $typ = <$typ>::from_bits(0);
+        ///Highest possible address
+        pub const LAST: $typ = <$typ>::from_bits($repr::MAX);
+
         #[inline]
         ///Computes network mask for provided `prefix`, assuming `prefix` is valid prefix
         pub const fn mask(prefix: u8) -> $typ {
@@ -101,6 +339,31 @@ macro_rules! impl_base_methods {
         }
 
         impl $crate::base::Cidr<$typ> {
+            ///Default route covering all addresses
+            pub const DEFAULT: Self = match Self::new(<$typ>::UNSPECIFIED, 0) {
+                Some(cidr) => cidr,
+                None => unreachable!(),
+            };
+
+            #[inline]
+            ///Constructs block of `prefix` length that contains `addr`, with host bits of `addr` cleared
+            ///
+            ///Returns `None` if `prefix` is greater than address length
+            pub const fn at_prefix(addr: $typ, prefix: u8) -> Option<Self> {
+                Self::new(network_addr(addr, prefix), prefix)
+            }
+
+            #[inline(always)]
+            ///Constructs new CIDR with host bits of `addr` cleared, so that stored address is the network address
+            ///
+            ///Blocks constructed from any address of the same network are equal.
+            ///Same as [at_prefix](#method.at_prefix)
+            ///
+            ///Returns `None` if `prefix` is greater than address length
+            pub const fn new_network(addr: $typ, prefix: u8) -> Option<Self> {
+                Self::at_prefix(addr, prefix)
+            }
+
             #[inline(always)]
             ///Computes network address from provided `addr` and `prefix`, which is lowest possible address within CIDR block
             pub const fn network_addr(&self) -> $typ {
@@ -113,20 +376,209 @@ macro_rules! impl_base_methods {
                 broadcast_addr(self.addr(), self.prefix())
             }
 
+            #[inline(always)]
+            ///Returns inclusive range of addresses within the block, from network address to broadcast address
+            pub const fn range(&self) -> ($typ, $typ) {
+                (self.network_addr(), self.broadcast_addr())
+            }
+
+            #[inline(always)]
+            ///Returns network mask of the block (e.g. `255.255.255.0` for `/24`)
+            pub const fn netmask(&self) -> $typ {
+                mask(self.prefix())
+            }
+
+            #[inline(always)]
+            ///Returns wildcard mask of the block, which is inverted network mask (e.g. `0.0.0.255` for `/24`)
+            pub const fn wildcard_mask(&self) -> $typ {
+                <$typ>::from_bits(!self.netmask().to_bits())
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            ///Returns textual representation of the block with host bits cleared (e.g. `2001:db8::/32` for `2001:db8::5/32`)
+            ///
+            ///Same as alternate form of `Display` (`{:#}`)
+            pub fn to_canonical_string(&self) -> alloc::string::String {
+                alloc::format!("{self:#}")
+            }
+
+            #[inline(always)]
+            ///Returns whether block is the default route covering all addresses, i.e. prefix is 0
+            pub const fn is_default(&self) -> bool {
+                self.prefix() == 0
+            }
+
+            #[inline(always)]
+            ///Returns whether block contains exactly one address, i.e. prefix equals address length
+            pub const fn is_host(&self) -> bool {
+                self.prefix() == BITS_LEN
+            }
+
+            #[inline(always)]
+            ///Returns whether stored address has no host bits set, i.e. it is the network address
+            pub const fn is_canonical(&self) -> bool {
+                self.addr().to_bits() == self.network_addr().to_bits()
+            }
+
+            #[inline]
+            ///Returns block with host bits of the stored address cleared, so that stored address is the network address
+            pub const fn canonical(&self) -> Self {
+                match Self::new(self.network_addr(), self.prefix()) {
+                    Some(cidr) => cidr,
+                    None => unreachable!(),
+                }
+            }
+
+            #[inline(always)]
+            ///Returns whether `self` and `other` are the same network, ignoring host bits of stored addresses
+            pub const fn same_network(&self, other: &Self) -> bool {
+                self.prefix() == other.prefix() && self.network_addr().to_bits() == other.network_addr().to_bits()
+            }
+
             #[inline(always)]
             ///Checks if a given `addr` is contained within `self`
             pub const fn contains(&self, addr: $typ) -> bool {
                 (addr.to_bits() & mask(self.prefix()).to_bits()) == self.network_addr().to_bits()
             }
 
+            #[inline]
+            ///Checks if all addresses of `other` are contained within `self`
+            ///
+            ///Block always contains itself
+            pub const fn contains_cidr(&self, other: &Self) -> bool {
+                self.prefix() <= other.prefix() && self.contains(other.network_addr())
+            }
+
+            #[inline(always)]
+            ///Checks if `self` and `other` have any address in common
+            ///
+            ///Blocks can only overlap if one contains the other
+            pub const fn overlaps(&self, other: &Self) -> bool {
+                self.contains_cidr(other) || other.contains_cidr(self)
+            }
+
+            #[inline]
+            ///Checks if `self` is a subnet of `parent`: it is fully contained within `parent` and its address is aligned to its prefix
+            pub const fn aligns_within(&self, parent: &Self) -> bool {
+                parent.prefix() <= self.prefix() && parent.contains(self.addr()) && self.is_canonical()
+            }
+
+            #[inline]
+            ///Returns block with prefix decreased by one, that contains `self`
+            ///
+            ///Returns `None` if prefix is already 0
+            pub const fn supernet(&self) -> Option<Self> {
+                match self.prefix() {
+                    0 => None,
+                    prefix => self.supernet_at(prefix - 1),
+                }
+            }
+
+            #[inline]
+            ///Returns block with shorter or equal `prefix`, that contains `self`
+            ///
+            ///Returns `None` if `prefix` is greater than prefix of the block
+            pub const fn supernet_at(&self, prefix: u8) -> Option<Self> {
+                if prefix > self.prefix() {
+                    return None;
+                }
+                Self::at_prefix(self.addr(), prefix)
+            }
+
+            #[inline]
+            ///Returns block of the same prefix that immediately follows `self`
+            ///
+            ///Returns `None` if `self` is the last block of the address space
+            pub const fn next(&self) -> Option<Self> {
+                if self.prefix() == 0 {
+                    return None;
+                }
+
+                match self.network_addr().to_bits().checked_add(size(self.prefix())) {
+                    Some(addr) => Self::new(<$typ>::from_bits(addr), self.prefix()),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            ///Returns block of the same prefix that immediately precedes `self`
+            ///
+            ///Returns `None` if `self` is the first block of the address space
+            pub const fn prev(&self) -> Option<Self> {
+                if self.prefix() == 0 {
+                    return None;
+                }
+
+                match self.network_addr().to_bits().checked_sub(size(self.prefix())) {
+                    Some(addr) => Self::new(<$typ>::from_bits(addr), self.prefix()),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            ///Splits block into two halves with prefix increased by one
+            ///
+            ///Element `0` is the lower half, starting at network address, while element `1` is the upper half.
+            ///
+            ///Returns `None` if prefix is already maximum
+            pub const fn halves(&self) -> Option<[Self; 2]> {
+                if self.prefix() >= BITS_LEN {
+                    return None;
+                }
+
+                let prefix = self.prefix() + 1;
+                let low = self.network_addr();
+                let high = <$typ>::from_bits(low.to_bits() | size(prefix));
+                match (Self::new(low, prefix), Self::new(high, prefix)) {
+                    (Some(low), Some(high)) => Some([low, high]),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            ///Splits block into lower and upper halves with prefix increased by one
+            ///
+            ///Same as [halves](#method.halves), returning tuple instead
+            pub const fn split(&self) -> Option<(Self, Self)> {
+                match self.halves() {
+                    Some([low, high]) => Some((low, high)),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            ///Returns prefix that divides block into at least `parts` equal subnets
+            ///
+            ///Number of subnets is `parts` rounded up to the next power of two.
+            ///
+            ///Returns `None` if `parts` is zero or exceeds number of addresses within the block
+            pub const fn even_split_prefix(&self, parts: u32) -> Option<u8> {
+                //Number of bits required to index `parts` subnets
+                let bits = match parts {
+                    0 => return None,
+                    parts => (u32::BITS - (parts - 1).leading_zeros()) as u8,
+                };
+
+                if bits > BITS_LEN - self.prefix() {
+                    return None;
+                }
+                Some(self.prefix() + bits)
+            }
+
             #[inline(always)]
             ///Returns number of possible addresses
+            ///
+            ///For `/0` number of addresses doesn't fit the address width, hence it saturates to maximum value,
+            ///which is one less than number of addresses yielded by [iter](#method.iter)
             pub const fn size(&self) -> $repr {
                 size(self.prefix())
             }
 
             #[inline(always)]
             ///Attempts to fetch address by `idx` within the block `self`
+            ///
+            ///Returns `None` if `idx` is not less than [size](#method.size), hence the last address of `/0` is not reachable
             pub const fn get(&self, idx: $repr) -> Option<$typ> {
                 if idx >= self.size() {
                     return None;
@@ -135,6 +587,56 @@ macro_rules! impl_base_methods {
                 Some(self.get_unchecked(idx))
             }
 
+            #[inline]
+            ///Returns offset of `addr` from network address, which is inverse of [get](#method.get)
+            ///
+            ///Returns `None` if `addr` is not within the block
+            pub const fn index_of(&self, addr: $typ) -> Option<$repr> {
+                if self.contains(addr) {
+                    Some(addr.to_bits() - self.network_addr().to_bits())
+                } else {
+                    None
+                }
+            }
+
+            #[inline(always)]
+            ///Returns iterator over all addresses within the block
+            pub const fn iter(&self) -> $crate::base::CidrIter<$typ> {
+                $crate::base::CidrIter {
+                    front: self.network_addr(),
+                    back: self.broadcast_addr(),
+                    exhausted: false,
+                }
+            }
+
+            #[inline]
+            ///Returns iterator over subnets of the block with `new_prefix`
+            ///
+            ///Returns `None` if `new_prefix` is less than prefix of the block or greater than address length
+            pub const fn subnets(&self, new_prefix: u8) -> Option<$crate::base::SubnetIter<$typ>> {
+                if new_prefix < self.prefix() || new_prefix > BITS_LEN {
+                    return None;
+                }
+
+                Some($crate::base::SubnetIter {
+                    front: self.network_addr(),
+                    back: network_addr(self.broadcast_addr(), new_prefix),
+                    prefix: new_prefix,
+                    exhausted: false,
+                })
+            }
+
+            #[inline]
+            ///Returns iterator over `count` equal subnets of the block, with `count` rounded up to the next power of two
+            ///
+            ///Returns `None` if `count` is zero or exceeds number of addresses within the block
+            pub const fn divide_into(&self, count: u32) -> Option<$crate::base::SubnetIter<$typ>> {
+                match self.even_split_prefix(count) {
+                    Some(prefix) => self.subnets(prefix),
+                    None => None,
+                }
+            }
+
             #[inline]
             ///Returns address corresponding `idx` without checking size according to the prefix
             ///
@@ -145,6 +647,151 @@ macro_rules! impl_base_methods {
                 <$typ>::from_bits(net.wrapping_add(idx))
             }
         }
+
+        impl core::fmt::LowerHex for $crate::base::Cidr<$typ> {
+            #[inline]
+            ///Writes zero padded hexadecimal bits of the address followed by `/prefix`
+            ///
+            ///Alternate form (`{:#x}`) prefixes address bits with `0x`
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                const WIDTH: usize = <$typ as $crate::base::NetworkAddress>::BITS_LEN as usize / 4;
+                if fmt.alternate() {
+                    fmt.write_str("0x")?;
+                }
+                fmt.write_fmt(format_args!("{:0WIDTH$x}/{}", self.addr().to_bits(), self.prefix()))
+            }
+        }
+
+        impl core::fmt::Binary for $crate::base::Cidr<$typ> {
+            #[inline]
+            ///Writes zero padded binary bits of the address followed by `/prefix`
+            ///
+            ///Alternate form (`{:#b}`) prefixes address bits with `0b`
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                const WIDTH: usize = <$typ as $crate::base::NetworkAddress>::BITS_LEN as usize;
+                if fmt.alternate() {
+                    fmt.write_str("0b")?;
+                }
+                fmt.write_fmt(format_args!("{:0WIDTH$b}/{}", self.addr().to_bits(), self.prefix()))
+            }
+        }
+
+        impl Iterator for $crate::base::CidrIter<$typ> {
+            type Item = $typ;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let addr = self.front;
+                if addr == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front = <$typ>::from_bits(addr.to_bits() + 1);
+                }
+                Some(addr)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    (0, Some(0))
+                } else {
+                    $crate::base::span_size_hint((self.back.to_bits() - self.front.to_bits()) as u128)
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for $crate::base::CidrIter<$typ> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let addr = self.back;
+                if addr == self.front {
+                    self.exhausted = true;
+                } else {
+                    self.back = <$typ>::from_bits(addr.to_bits() - 1);
+                }
+                Some(addr)
+            }
+        }
+
+        impl ExactSizeIterator for $crate::base::CidrIter<$typ> {
+            #[inline]
+            ///Returns number of remaining addresses, saturating at `usize::MAX`
+            fn len(&self) -> usize {
+                self.size_hint().0
+            }
+        }
+
+        impl core::iter::FusedIterator for $crate::base::CidrIter<$typ> {
+        }
+
+        impl Iterator for $crate::base::SubnetIter<$typ> {
+            type Item = $crate::base::Cidr<$typ>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let addr = self.front;
+                if addr == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front = <$typ>::from_bits(addr.to_bits() + size(self.prefix));
+                }
+                <$crate::base::Cidr<$typ>>::new(addr, self.prefix)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+
+                //Single subnet when prefix is 0, which cannot be shifted
+                let span = match (self.back.to_bits() - self.front.to_bits()).checked_shr((BITS_LEN - self.prefix) as u32) {
+                    Some(span) => span,
+                    None => 0,
+                };
+                $crate::base::span_size_hint(span as u128)
+            }
+        }
+
+        impl DoubleEndedIterator for $crate::base::SubnetIter<$typ> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+
+                let addr = self.back;
+                if addr == self.front {
+                    self.exhausted = true;
+                } else {
+                    self.back = <$typ>::from_bits(addr.to_bits() - size(self.prefix));
+                }
+                <$crate::base::Cidr<$typ>>::new(addr, self.prefix)
+            }
+        }
+
+        impl ExactSizeIterator for $crate::base::SubnetIter<$typ> {
+            #[inline]
+            ///Returns number of remaining subnets, saturating at `usize::MAX`
+            fn len(&self) -> usize {
+                self.size_hint().0
+            }
+        }
+
+        impl core::iter::FusedIterator for $crate::base::SubnetIter<$typ> {
+        }
     }
 }
 
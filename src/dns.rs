@@ -0,0 +1,53 @@
+use core::fmt::Write;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Cidr;
+
+//Writes reverse zone name of the canonical `block` aligned to `label_bits`
+fn write_zone(out: &mut String, block: &Cidr, label_bits: u8) {
+    let (bits, bits_len, suffix) = match block {
+        Cidr::V4(cidr) => (cidr.network_addr().to_bits() as u128, Cidr::IPV4_BITS, "in-addr.arpa"),
+        Cidr::V6(cidr) => (cidr.network_addr().to_bits(), Cidr::IPV6_BITS, "ip6.arpa"),
+    };
+    let label_mask = (1u128 << label_bits) - 1;
+
+    for idx in (0..block.prefix() / label_bits).rev() {
+        let label = (bits >> (bits_len - (idx + 1) * label_bits)) & label_mask;
+        let _ = match block {
+            Cidr::V4(_) => write!(out, "{label}."),
+            Cidr::V6(_) => write!(out, "{label:x}."),
+        };
+    }
+    out.push_str(suffix);
+}
+
+impl Cidr {
+    ///Returns reverse DNS zone names covering the block
+    ///
+    ///Zones are delegated on label boundary, which is octet for IPv4 (`in-addr.arpa`) and nibble for IPv6 (`ip6.arpa`):
+    ///
+    ///- If prefix is aligned to label boundary, result is single zone (e.g. `0.168.192.in-addr.arpa` for `192.168.0.0/24`);
+    ///- Otherwise prefix is rounded up to the next boundary and result lists all zones of that prefix within the block
+    ///(e.g. 16 zones from `0.16.172.in-addr.arpa` to `15.16.172.in-addr.arpa` for `172.16.0.0/20`).
+    ///
+    ///Zones are listed in ascending order of their network address.
+    pub fn reverse_dns_zones(&self) -> Vec<String> {
+        let label_bits = match self {
+            Self::V4(_) => 8,
+            Self::V6(_) => 4,
+        };
+        let aligned_prefix = self.prefix().next_multiple_of(label_bits);
+
+        let mut result = Vec::new();
+        if let Some(subnets) = self.subnets(aligned_prefix) {
+            result.reserve_exact(subnets.len());
+            for block in subnets {
+                let mut zone = String::new();
+                write_zone(&mut zone, &block, label_bits);
+                result.push(zone);
+            }
+        }
+        result
+    }
+}
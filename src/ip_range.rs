@@ -0,0 +1,278 @@
+use core::{fmt, net};
+
+use crate::{AddrFamily, Cidr};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Error indicating invalid range of addresses
+pub enum RangeError {
+    ///Range contains no addresses
+    Empty,
+    ///Range extends past the highest address of the family
+    Overflow,
+    ///Range bounds are of different address families
+    FamilyMismatch,
+}
+
+impl fmt::Display for RangeError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => fmt.write_str("Range contains no addresses"),
+            Self::Overflow => fmt.write_str("Range extends past the highest address"),
+            Self::FamilyMismatch => fmt.write_str("Range bounds are of different address families"),
+        }
+    }
+}
+
+impl core::error::Error for RangeError {
+}
+
+#[inline(always)]
+//Returns bits of `addr`, with IPv4 occupying low 32 bits
+pub(crate) const fn addr_bits(addr: net::IpAddr) -> u128 {
+    match addr {
+        net::IpAddr::V4(addr) => addr.to_bits() as _,
+        net::IpAddr::V6(addr) => addr.to_bits(),
+    }
+}
+
+#[inline(always)]
+//`bits` must not exceed the highest address of the `family`
+pub(crate) const fn bits_addr(family: AddrFamily, bits: u128) -> net::IpAddr {
+    match family {
+        AddrFamily::V4 => net::IpAddr::V4(net::Ipv4Addr::from_bits(bits as _)),
+        AddrFamily::V6 => net::IpAddr::V6(net::Ipv6Addr::from_bits(bits)),
+    }
+}
+
+#[inline(always)]
+const fn addr_family(addr: net::IpAddr) -> AddrFamily {
+    match addr {
+        net::IpAddr::V4(_) => AddrFamily::V4,
+        net::IpAddr::V6(_) => AddrFamily::V6,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Inclusive range of addresses of the same family, which is not necessarily aligned to any prefix
+pub struct IpRange {
+    start: net::IpAddr,
+    end: net::IpAddr,
+}
+
+impl IpRange {
+    #[inline]
+    ///Constructs range of addresses from `start` to `end` inclusive
+    ///
+    ///Returns `Err` if `end` is less than `start` or addresses are of different families
+    pub const fn new(start: net::IpAddr, end: net::IpAddr) -> Result<Self, RangeError> {
+        match (start, end) {
+            (net::IpAddr::V4(_), net::IpAddr::V4(_)) | (net::IpAddr::V6(_), net::IpAddr::V6(_)) => (),
+            _ => return Err(RangeError::FamilyMismatch),
+        }
+
+        if addr_bits(end) < addr_bits(start) {
+            Err(RangeError::Empty)
+        } else {
+            Ok(Self {
+                start,
+                end,
+            })
+        }
+    }
+
+    #[inline(always)]
+    ///Returns first address of the range
+    pub const fn start(&self) -> net::IpAddr {
+        self.start
+    }
+
+    #[inline(always)]
+    ///Returns last address of the range
+    pub const fn end(&self) -> net::IpAddr {
+        self.end
+    }
+
+    #[inline(always)]
+    ///Returns address family
+    pub const fn family(&self) -> AddrFamily {
+        addr_family(self.start)
+    }
+
+    #[inline]
+    ///Constructs range from integer bounds as returned by [to_bounds](#method.to_bounds)
+    ///
+    ///Returns `Err` if `hi` is less than `lo` or bounds do not fit addresses of the `family`
+    pub const fn from_bounds(family: AddrFamily, lo: u128, hi: u128) -> Result<Self, RangeError> {
+        if hi < lo {
+            return Err(RangeError::Empty);
+        }
+        match family {
+            AddrFamily::V4 if hi > u32::MAX as u128 => Err(RangeError::Overflow),
+            family => Ok(Self {
+                start: bits_addr(family, lo),
+                end: bits_addr(family, hi),
+            }),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns first and last address of the range as integers, suitable for interval storage
+    ///
+    ///IPv4 addresses occupy low 32 bits
+    pub const fn to_bounds(&self) -> (u128, u128) {
+        (addr_bits(self.start), addr_bits(self.end))
+    }
+
+    #[inline]
+    const fn is_same_family(&self, other: &Self) -> bool {
+        matches!((self.start, other.start), (net::IpAddr::V4(_), net::IpAddr::V4(_)) | (net::IpAddr::V6(_), net::IpAddr::V6(_)))
+    }
+
+    #[inline]
+    ///Returns range of addresses within both `self` and `other`
+    ///
+    ///Returns `None` if ranges have no address in common or are of different families
+    pub const fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.is_same_family(other) {
+            return None;
+        }
+
+        let (start, end) = self.to_bounds();
+        let (other_start, other_end) = other.to_bounds();
+        let start = if start > other_start { start } else { other_start };
+        let end = if end < other_end { end } else { other_end };
+        if start > end {
+            None
+        } else {
+            let family = self.family();
+            Some(Self {
+                start: bits_addr(family, start),
+                end: bits_addr(family, end),
+            })
+        }
+    }
+
+    #[inline]
+    ///Returns range of addresses within either `self` or `other`
+    ///
+    ///Adjacent ranges are merged, e.g. `10.0.0.1-10.0.0.5` and `10.0.0.6-10.0.0.10` result in `10.0.0.1-10.0.0.10`.
+    ///
+    ///Returns `None` if there is a gap between ranges, hence union is not a single range, or ranges are of different families
+    pub const fn union(&self, other: &Self) -> Option<Self> {
+        if !self.is_same_family(other) {
+            return None;
+        }
+
+        let (low, high) = if self.to_bounds().0 <= other.to_bounds().0 { (self, other) } else { (other, self) };
+        let (_, low_end) = low.to_bounds();
+        let (high_start, high_end) = high.to_bounds();
+        if high_start > low_end && high_start - low_end > 1 {
+            None
+        } else if high_end > low_end {
+            Some(Self {
+                start: low.start,
+                end: high.end,
+            })
+        } else {
+            Some(*low)
+        }
+    }
+
+    #[inline]
+    ///Checks if a given `addr` is contained within `self`
+    pub const fn contains(&self, addr: net::IpAddr) -> bool {
+        match (self.start, addr) {
+            (net::IpAddr::V4(_), net::IpAddr::V4(_)) | (net::IpAddr::V6(_), net::IpAddr::V6(_)) => {
+                let (start, end) = self.to_bounds();
+                let addr = addr_bits(addr);
+                start <= addr && addr <= end
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Cidr {
+    #[inline]
+    ///Returns range covering both `self` and `range` when they overlap or are adjacent
+    ///
+    ///Returns `None` if there is a gap between them or they are of different families
+    pub const fn extend_to_range(&self, range: &IpRange) -> Option<IpRange> {
+        let this = IpRange {
+            start: self.network_addr(),
+            end: self.broadcast_addr(),
+        };
+        this.union(range)
+    }
+}
+
+impl From<Cidr> for IpRange {
+    #[inline]
+    fn from(cidr: Cidr) -> Self {
+        Self {
+            start: cidr.network_addr(),
+            end: cidr.broadcast_addr(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Iterator over prefix aligned network addresses within [IpRange](struct.IpRange.html)
+pub struct AlignedIter {
+    family: AddrFamily,
+    front: Option<u128>,
+    end: u128,
+    host_mask: u128,
+}
+
+impl Iterator for AlignedIter {
+    type Item = net::IpAddr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let front = self.front?;
+        if front > self.end {
+            self.front = None;
+            return None;
+        }
+
+        self.front = match front.checked_add(self.host_mask) {
+            Some(last) => last.checked_add(1),
+            None => None,
+        };
+        Some(bits_addr(self.family, front))
+    }
+}
+
+///Returns iterator over network addresses of `/prefix` blocks that start within `range`
+///
+///First address is the lowest `/prefix` aligned address not less than start of the range,
+///hence iterator is empty when range contains no aligned address.
+///
+///Returns `None` if `prefix` is greater than address length
+pub const fn aligned_boundaries(range: &IpRange, prefix: u8) -> Option<AlignedIter> {
+    let family = range.family();
+    let bits_len = match family {
+        AddrFamily::V4 => Cidr::IPV4_BITS,
+        AddrFamily::V6 => Cidr::IPV6_BITS,
+    };
+    if prefix > bits_len {
+        return None;
+    }
+
+    let host_mask = match bits_len - prefix {
+        0 => 0,
+        host_bits => u128::MAX >> (u128::BITS - host_bits as u32),
+    };
+    let front = match addr_bits(range.start).checked_add(host_mask) {
+        Some(front) => Some(front & !host_mask),
+        None => None,
+    };
+    Some(AlignedIter {
+        family,
+        front,
+        end: addr_bits(range.end),
+        host_mask,
+    })
+}
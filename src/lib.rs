@@ -3,17 +3,283 @@
 #![no_std]
 #![warn(missing_docs)]
 #![allow(clippy::style)]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod parser;
-pub use parser::{parse_ip, ParseError};
+pub use parser::{parse_ip, parse_ip_verbose, parse_ip_zoned, ParseError, VerboseParseError};
+#[cfg(feature = "alloc")]
+pub use parser::OwnedParseError;
 pub mod base;
+pub use base::PrefixError;
 pub mod v4;
 pub mod v6;
+mod aggregate;
+pub use aggregate::aggregate_into;
+#[cfg(feature = "alloc")]
+pub use aggregate::aggregate;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "nightly")]
+mod step;
+#[cfg(feature = "nightly")]
+pub use step::StepAddr;
+#[cfg(feature = "alloc")]
+mod plan;
+#[cfg(feature = "alloc")]
+pub use plan::plan_subnets;
+mod ip_range;
+pub use ip_range::{aligned_boundaries, AlignedIter, IpRange, RangeError};
+#[cfg(feature = "alloc")]
+mod range;
+#[cfg(feature = "alloc")]
+mod dns;
+#[cfg(feature = "alloc")]
+mod set;
+#[cfg(feature = "alloc")]
+pub use set::{CidrSet, CidrSetBuilder, CidrSetIter, DecodeError, MatchIter, RangeIter};
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+pub use map::CidrMap;
+#[cfg(feature = "alloc")]
+pub use range::{cidrs_for_count, summarize_range};
 
 use core::{fmt, net};
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///IP address family
+pub enum AddrFamily {
+    ///IPv4
+    V4,
+    ///IPv6
+    V6,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Textual representation of [Cidr](enum.Cidr.html)
+pub enum DisplayStyle {
+    ///`addr/prefix`, same as `Display`
+    Canonical,
+    ///`addr netmask` (e.g. `192.168.1.0 255.255.255.0`)
+    ///
+    ///Applies only to IPv4, IPv6 is written in canonical form.
+    Netmask,
+    ///`addr wildcard` (e.g. `192.168.1.0 0.0.0.255`)
+    ///
+    ///Applies only to IPv4, IPv6 is written in canonical form.
+    Wildcard,
+    ///IPv6 address written without compression and with leading zeros (e.g. `2001:0db8:0000:0000:0000:0000:0000:0000/32`)
+    ///
+    ///Applies only to IPv6, IPv4 is written in canonical form.
+    Expanded,
+}
+
+#[derive(Copy, Clone, Debug)]
+///[Cidr](enum.Cidr.html) formatted according to [DisplayStyle](enum.DisplayStyle.html)
+pub struct CidrDisplay {
+    cidr: Cidr,
+    style: DisplayStyle,
+}
+
+impl fmt::Display for CidrDisplay {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.cidr, self.style) {
+            (Cidr::V4(cidr), DisplayStyle::Netmask) => {
+                fmt.write_fmt(format_args!("{} {}", cidr.addr(), cidr.netmask()))
+            },
+            (Cidr::V4(cidr), DisplayStyle::Wildcard) => {
+                fmt.write_fmt(format_args!("{} {}", cidr.addr(), cidr.wildcard_mask()))
+            },
+            (Cidr::V6(cidr), DisplayStyle::Expanded) => {
+                let [a, b, c, d, e, f, g, h] = cidr.addr().segments();
+                let prefix = cidr.prefix();
+                fmt.write_fmt(format_args!("{a:04x}:{b:04x}:{c:04x}:{d:04x}:{e:04x}:{f:04x}:{g:04x}:{h:04x}/{prefix}"))
+            },
+            (cidr, _) => fmt::Display::fmt(&cidr, fmt),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Properties of [Cidr](enum.Cidr.html) block, as shown by subnet calculator
+///
+///All addresses of IPv6 block are considered usable hosts, while IPv4 block excludes network and broadcast addresses unless prefix is 31 or 32 (RFC 3021)
+pub struct BlockSummary {
+    ///Lowest address within the block
+    pub network: net::IpAddr,
+    ///Highest address within the block
+    pub broadcast: net::IpAddr,
+    ///Lowest usable host address
+    pub first_host: net::IpAddr,
+    ///Highest usable host address
+    pub last_host: net::IpAddr,
+    ///Number of usable host addresses
+    pub usable_hosts: u128,
+    ///Number of addresses within the block, same as [Cidr::size](enum.Cidr.html#method.size)
+    pub size: u128,
+    ///Network mask
+    pub netmask: net::IpAddr,
+    ///Wildcard mask, which is inverted network mask
+    pub wildcard: net::IpAddr,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Octets of the address stored in [Cidr](enum.Cidr.html), which are 4 for IPv4 and 16 for IPv6
+pub struct AddrOctets {
+    len: u8,
+    octets: [u8; 16],
+}
+
+impl AddrOctets {
+    #[inline(always)]
+    ///Returns octets in network byte order
+    pub const fn as_slice(&self) -> &[u8] {
+        self.octets.split_at(self.len as usize).0
+    }
+}
+
+impl core::ops::Deref for AddrOctets {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for AddrOctets {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[derive(Clone, Debug)]
+///Iterator over addresses of the [Cidr](enum.Cidr.html) block, from network address to broadcast address
+///
+///Number of remaining addresses is exact only when it fits `usize`, otherwise `len()` is `usize::MAX`
+///and `size_hint()` has no upper bound, as with large IPv6 blocks.
+pub enum CidrIter {
+    ///IPv4 addresses
+    V4(v4::CidrIter),
+    ///IPv6 addresses
+    V6(v6::CidrIter),
+}
+
+impl Iterator for CidrIter {
+    type Item = net::IpAddr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::V4(iter) => iter.next().map(net::IpAddr::V4),
+            Self::V6(iter) => iter.next().map(net::IpAddr::V6),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::V4(iter) => iter.size_hint(),
+            Self::V6(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for CidrIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::V4(iter) => iter.next_back().map(net::IpAddr::V4),
+            Self::V6(iter) => iter.next_back().map(net::IpAddr::V6),
+        }
+    }
+}
+
+impl ExactSizeIterator for CidrIter {
+    #[inline]
+    ///Returns number of remaining addresses, saturating at `usize::MAX`
+    fn len(&self) -> usize {
+        match self {
+            Self::V4(iter) => iter.len(),
+            Self::V6(iter) => iter.len(),
+        }
+    }
+}
+
+impl core::iter::FusedIterator for CidrIter {
+}
+
+#[derive(Clone, Debug)]
+///Iterator over subnets of the [Cidr](enum.Cidr.html) block with the same prefix, in ascending order
+///
+///Number of remaining subnets is exact only when it fits `usize`, otherwise `len()` is `usize::MAX`
+///and `size_hint()` has no upper bound, as with large IPv6 splits.
+pub enum SubnetIter {
+    ///IPv4 subnets
+    V4(v4::SubnetIter),
+    ///IPv6 subnets
+    V6(v6::SubnetIter),
+}
+
+impl Iterator for SubnetIter {
+    type Item = Cidr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::V4(iter) => iter.next().map(Cidr::V4),
+            Self::V6(iter) => iter.next().map(Cidr::V6),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::V4(iter) => iter.size_hint(),
+            Self::V6(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for SubnetIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::V4(iter) => iter.next_back().map(Cidr::V4),
+            Self::V6(iter) => iter.next_back().map(Cidr::V6),
+        }
+    }
+}
+
+impl ExactSizeIterator for SubnetIter {
+    #[inline]
+    ///Returns number of remaining subnets, saturating at `usize::MAX`
+    fn len(&self) -> usize {
+        match self {
+            Self::V4(iter) => iter.len(),
+            Self::V6(iter) => iter.len(),
+        }
+    }
+}
+
+impl core::iter::FusedIterator for SubnetIter {
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 ///CIDR representation
+///
+///IPv4 blocks are ordered before IPv6 blocks, then blocks are ordered by prefix and stored address.
+///Use [sort_key](#method.sort_key) to order blocks by network address.
 pub enum Cidr {
     ///IPv4 block
     V4(v4::Cidr),
@@ -38,6 +304,23 @@ impl Cidr {
         }
     }
 
+    #[inline]
+    ///Constructs new CIDR verifying that `prefix` fits provided `addrs`
+    ///
+    ///Returns `Err` describing the maximum allowed prefix if `prefix` is greater than address length
+    pub const fn try_new(addr: net::IpAddr, prefix: u8) -> Result<Self, PrefixError> {
+        match addr {
+            net::IpAddr::V4(addr) => match v4::Cidr::try_new(addr, prefix) {
+                Ok(cidr) => Ok(Self::V4(cidr)),
+                Err(error) => Err(error),
+            },
+            net::IpAddr::V6(addr) => match v6::Cidr::try_new(addr, prefix) {
+                Ok(cidr) => Ok(Self::V6(cidr)),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
     #[inline]
     ///Constructs new CIDR verifying that `prefix` fits provided `addrs`
     ///
@@ -60,6 +343,123 @@ impl Cidr {
         }
     }
 
+    ///Constructs new CIDR from address octets in network byte order, treating 4 octets as IPv4 and 16 octets as IPv6
+    ///
+    ///Returns `None` if length of `bytes` is neither 4 nor 16, or `prefix` is greater than address length
+    pub const fn from_bytes(bytes: &[u8], prefix: u8) -> Option<Self> {
+        match bytes.len() {
+            4 => Self::new_v4(net::Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]), prefix),
+            16 => {
+                let mut octets = [0u8; 16];
+                let mut idx = 0;
+                while idx < octets.len() {
+                    octets[idx] = bytes[idx];
+                    idx += 1;
+                }
+                Self::new_v6(net::Ipv6Addr::from_bits(u128::from_be_bytes(octets)), prefix)
+            },
+            _ => None,
+        }
+    }
+
+    #[inline]
+    ///Returns octets of the stored address in network byte order, which are inverse of [from_bytes](#method.from_bytes)
+    pub const fn to_bytes(&self) -> AddrOctets {
+        match self {
+            Self::V4(cidr) => {
+                let [a, b, c, d] = cidr.addr().octets();
+                AddrOctets {
+                    len: 4,
+                    octets: [a, b, c, d, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                }
+            },
+            Self::V6(cidr) => AddrOctets {
+                len: 16,
+                octets: cidr.addr().octets(),
+            },
+        }
+    }
+
+    #[inline(always)]
+    ///Returns IPv4 default route `0.0.0.0/0`
+    pub const fn default_v4() -> Self {
+        Self::V4(v4::Cidr::DEFAULT)
+    }
+
+    #[inline(always)]
+    ///Returns IPv6 default route `::/0`
+    pub const fn default_v6() -> Self {
+        Self::V6(v6::Cidr::DEFAULT)
+    }
+
+    #[inline]
+    ///Constructs block of `prefix` length that contains `addr`, with host bits of `addr` cleared
+    ///
+    ///Returns `None` if `prefix` is greater than address length
+    pub const fn at_prefix(addr: net::IpAddr, prefix: u8) -> Option<Self> {
+        match addr {
+            net::IpAddr::V4(addr) => match v4::Cidr::at_prefix(addr, prefix) {
+                Some(cidr) => Some(Self::V4(cidr)),
+                None => None,
+            },
+            net::IpAddr::V6(addr) => match v6::Cidr::at_prefix(addr, prefix) {
+                Some(cidr) => Some(Self::V6(cidr)),
+                None => None,
+            },
+        }
+    }
+
+    #[inline(always)]
+    ///Constructs new CIDR with host bits of `addr` cleared, so that stored address is the network address
+    ///
+    ///Blocks constructed from any address of the same network are equal, unlike with [new](#method.new), which preserves `addr` as is.
+    ///Same as [at_prefix](#method.at_prefix)
+    ///
+    ///Returns `None` if `prefix` is greater than address length
+    pub const fn new_network(addr: net::IpAddr, prefix: u8) -> Option<Self> {
+        Self::at_prefix(addr, prefix)
+    }
+
+    #[inline]
+    ///Constructs new IPv4 CIDR with host bits of `addr` cleared
+    ///
+    ///Returns `None` if `prefix` is greater than address length
+    pub const fn new_network_v4(addr: net::Ipv4Addr, prefix: u8) -> Option<Self> {
+        match v4::Cidr::new_network(addr, prefix) {
+            Some(cidr) => Some(Self::V4(cidr)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Constructs new IPv6 CIDR with host bits of `addr` cleared
+    ///
+    ///Returns `None` if `prefix` is greater than address length
+    pub const fn new_network_v6(addr: net::Ipv6Addr, prefix: u8) -> Option<Self> {
+        match v6::Cidr::new_network(addr, prefix) {
+            Some(cidr) => Some(Self::V6(cidr)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether `prefix` fits address of the `family`
+    pub const fn is_valid_prefix(family: AddrFamily, prefix: u8) -> bool {
+        match family {
+            AddrFamily::V4 => v4::is_valid_prefix(prefix),
+            AddrFamily::V6 => v6::is_valid_prefix(prefix),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns lowest and highest possible addresses of the `family`
+    pub const fn family_bounds(family: AddrFamily) -> (net::IpAddr, net::IpAddr) {
+        match family {
+            AddrFamily::V4 => (net::IpAddr::V4(v4::FIRST), net::IpAddr::V4(v4::LAST)),
+            AddrFamily::V6 => (net::IpAddr::V6(v6::FIRST), net::IpAddr::V6(v6::LAST)),
+        }
+    }
+
     #[inline(always)]
     ///Returns address
     pub const fn addr(&self) -> net::IpAddr {
@@ -69,6 +469,15 @@ impl Cidr {
         }
     }
 
+    #[inline(always)]
+    ///Returns address family
+    pub const fn family(&self) -> AddrFamily {
+        match self {
+            Self::V4(_) => AddrFamily::V4,
+            Self::V6(_) => AddrFamily::V6,
+        }
+    }
+
     #[inline(always)]
     ///Returns prefix
     pub const fn prefix(&self) -> u8 {
@@ -96,6 +505,94 @@ impl Cidr {
         }
     }
 
+    #[inline(always)]
+    ///Returns inclusive range of addresses within the block, from network address to broadcast address
+    ///
+    ///```
+    ///use core::net::{IpAddr, Ipv4Addr};
+    ///
+    ///const RANGE: (IpAddr, IpAddr) = ip_cidr::cidr!("10.0.0.0/30").range();
+    ///assert_eq!(RANGE, (IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3))));
+    ///```
+    pub const fn range(&self) -> (net::IpAddr, net::IpAddr) {
+        match self {
+            Self::V4(cidr) => (net::IpAddr::V4(cidr.network_addr()), net::IpAddr::V4(cidr.broadcast_addr())),
+            Self::V6(cidr) => (net::IpAddr::V6(cidr.network_addr()), net::IpAddr::V6(cidr.broadcast_addr())),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns network mask of the block (e.g. `255.255.255.0` for `/24`)
+    pub const fn netmask(&self) -> net::IpAddr {
+        match self {
+            Self::V4(cidr) => net::IpAddr::V4(cidr.netmask()),
+            Self::V6(cidr) => net::IpAddr::V6(cidr.netmask()),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns wildcard mask of the block, which is inverted network mask (e.g. `0.0.0.255` for `/24`)
+    pub const fn wildcard_mask(&self) -> net::IpAddr {
+        match self {
+            Self::V4(cidr) => net::IpAddr::V4(cidr.wildcard_mask()),
+            Self::V6(cidr) => net::IpAddr::V6(cidr.wildcard_mask()),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether block is the default route covering all addresses of its family (`0.0.0.0/0` or `::/0`)
+    pub const fn is_default(&self) -> bool {
+        match self {
+            Self::V4(cidr) => cidr.is_default(),
+            Self::V6(cidr) => cidr.is_default(),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether block contains exactly one address, i.e. prefix is 32 for IPv4 and 128 for IPv6
+    ///
+    ///This is the case for CIDR parsed without prefix.
+    pub const fn is_host(&self) -> bool {
+        match self {
+            Self::V4(cidr) => cidr.is_host(),
+            Self::V6(cidr) => cidr.is_host(),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether stored address has no host bits set, i.e. it is the network address
+    pub const fn is_canonical(&self) -> bool {
+        match self {
+            Self::V4(cidr) => cidr.is_canonical(),
+            Self::V6(cidr) => cidr.is_canonical(),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns block with host bits of the stored address cleared, so that stored address is the network address
+    ///
+    ///Blocks are compared by stored address, hence `10.0.0.5/24` and `10.0.0.9/24` are not equal,
+    ///while their canonical blocks are both `10.0.0.0/24`.
+    pub const fn canonical(&self) -> Self {
+        match self {
+            Self::V4(cidr) => Self::V4(cidr.canonical()),
+            Self::V6(cidr) => Self::V6(cidr.canonical()),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether `self` and `other` are the same network, ignoring host bits of stored addresses
+    ///
+    ///Unlike `PartialEq`, which compares stored addresses to preserve input as is,
+    ///this compares [canonical](#method.canonical) blocks (e.g. `10.0.0.5/24` is the same network as `10.0.0.9/24`).
+    pub const fn same_network(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::V4(this), Self::V4(other)) => this.same_network(other),
+            (Self::V6(this), Self::V6(other)) => this.same_network(other),
+            _ => false,
+        }
+    }
+
     #[inline(always)]
     ///Returns maximum number of addresses within the block
     pub const fn size(&self) -> u128 {
@@ -105,6 +602,55 @@ impl Cidr {
         }
     }
 
+    #[inline]
+    ///Returns whether network address is private
+    ///
+    ///- For IPv4 it is one of `10.0.0.0/8`, `172.16.0.0/12` or `192.168.0.0/16` (RFC 1918)
+    ///- For IPv6 it is unique local address within `fc00::/7` (RFC 4193)
+    pub const fn is_private(&self) -> bool {
+        match self {
+            Self::V4(cidr) => cidr.network_addr().is_private(),
+            Self::V6(cidr) => cidr.network_addr().segments()[0] & 0xfe00 == 0xfc00,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether network address is loopback address
+    pub const fn is_loopback(&self) -> bool {
+        self.network_addr().is_loopback()
+    }
+
+    #[inline(always)]
+    ///Returns whether network address is multicast address
+    pub const fn is_multicast(&self) -> bool {
+        self.network_addr().is_multicast()
+    }
+
+    #[inline(always)]
+    ///Returns whether network address is unspecified address
+    pub const fn is_unspecified(&self) -> bool {
+        self.network_addr().is_unspecified()
+    }
+
+    ///Returns whether block partially overlaps [special-purpose](constant.SPECIAL_PURPOSE.html) blocks
+    ///
+    ///Such block is neither fully global nor fully reserved, which usually indicates misconfiguration,
+    ///e.g. supernet accidentally including private space.
+    ///Block fully inside any special-purpose block never straddles, even if it overlaps nested blocks.
+    pub const fn straddles_reserved(&self) -> bool {
+        let mut overlaps = false;
+        let mut idx = 0;
+        while idx < SPECIAL_PURPOSE.len() {
+            let reserved = &SPECIAL_PURPOSE[idx];
+            if reserved.contains_cidr(self) {
+                return false;
+            }
+            overlaps = overlaps || self.overlaps(reserved);
+            idx += 1;
+        }
+        overlaps
+    }
+
     #[inline(always)]
     ///Checks if a given `addr` is contained within `self`
     pub const fn contains(&self, addr: net::IpAddr) -> bool {
@@ -115,6 +661,137 @@ impl Cidr {
         }
     }
 
+    #[inline(always)]
+    ///Checks if all addresses of `other` are contained within `self`
+    ///
+    ///Block always contains itself, while blocks of different families never contain each other
+    pub const fn contains_cidr(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::V4(cidr), Self::V4(other)) => cidr.contains_cidr(other),
+            (Self::V6(cidr), Self::V6(other)) => cidr.contains_cidr(other),
+            _ => false,
+        }
+    }
+
+    #[inline(always)]
+    ///Checks if `self` and `other` have any address in common
+    ///
+    ///Blocks of different families never overlap
+    pub const fn overlaps(&self, other: &Self) -> bool {
+        self.contains_cidr(other) || other.contains_cidr(self)
+    }
+
+    #[inline(always)]
+    ///Checks if `self` is a subnet of `parent`: it is fully contained within `parent` and its address is aligned to its prefix
+    ///
+    ///Blocks of different families are never aligned
+    pub const fn aligns_within(&self, parent: &Self) -> bool {
+        match (self, parent) {
+            (Self::V4(cidr), Self::V4(parent)) => cidr.aligns_within(parent),
+            (Self::V6(cidr), Self::V6(parent)) => cidr.aligns_within(parent),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    ///Returns block with prefix decreased by one, that contains `self`
+    ///
+    ///Returns `None` if prefix is already 0
+    pub const fn supernet(&self) -> Option<Self> {
+        match self.prefix() {
+            0 => None,
+            prefix => self.supernet_at(prefix - 1),
+        }
+    }
+
+    #[inline]
+    ///Returns block with shorter or equal `prefix`, that contains `self`
+    ///
+    ///Returns `None` if `prefix` is greater than prefix of the block
+    pub const fn supernet_at(&self, prefix: u8) -> Option<Self> {
+        if prefix > self.prefix() {
+            return None;
+        }
+        Self::at_prefix(self.addr(), prefix)
+    }
+
+    #[inline]
+    ///Returns block of the same prefix that immediately follows `self`
+    ///
+    ///Returns `None` if `self` is the last block of the address space
+    pub const fn next(&self) -> Option<Self> {
+        match self {
+            Self::V4(cidr) => match cidr.next() {
+                Some(cidr) => Some(Self::V4(cidr)),
+                None => None,
+            },
+            Self::V6(cidr) => match cidr.next() {
+                Some(cidr) => Some(Self::V6(cidr)),
+                None => None,
+            },
+        }
+    }
+
+    #[inline]
+    ///Returns block of the same prefix that immediately precedes `self`
+    ///
+    ///Returns `None` if `self` is the first block of the address space
+    pub const fn prev(&self) -> Option<Self> {
+        match self {
+            Self::V4(cidr) => match cidr.prev() {
+                Some(cidr) => Some(Self::V4(cidr)),
+                None => None,
+            },
+            Self::V6(cidr) => match cidr.prev() {
+                Some(cidr) => Some(Self::V6(cidr)),
+                None => None,
+            },
+        }
+    }
+
+    #[inline]
+    ///Splits block into two halves with prefix increased by one
+    ///
+    ///Element `0` is the lower half, starting at network address, while element `1` is the upper half.
+    ///
+    ///Returns `None` if prefix is already maximum
+    pub const fn halves(&self) -> Option<[Self; 2]> {
+        match self {
+            Self::V4(cidr) => match cidr.halves() {
+                Some([low, high]) => Some([Self::V4(low), Self::V4(high)]),
+                None => None,
+            },
+            Self::V6(cidr) => match cidr.halves() {
+                Some([low, high]) => Some([Self::V6(low), Self::V6(high)]),
+                None => None,
+            },
+        }
+    }
+
+    #[inline]
+    ///Splits block into lower and upper halves with prefix increased by one (e.g. `10.0.0.0/25` and `10.0.0.128/25` for `10.0.0.0/24`)
+    ///
+    ///Same as [halves](#method.halves), returning tuple instead
+    pub const fn split(&self) -> Option<(Self, Self)> {
+        match self.halves() {
+            Some([low, high]) => Some((low, high)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns prefix that divides block into at least `parts` equal subnets
+    ///
+    ///Number of subnets is `parts` rounded up to the next power of two.
+    ///
+    ///Returns `None` if `parts` is zero or exceeds number of addresses within the block
+    pub const fn even_split_prefix(&self, parts: u32) -> Option<u8> {
+        match self {
+            Self::V4(cidr) => cidr.even_split_prefix(parts),
+            Self::V6(cidr) => cidr.even_split_prefix(parts),
+        }
+    }
+
     #[inline(always)]
     ///Attempts to fetch address by `idx` within the block `self`
     pub const fn get(&self, idx: u128) -> Option<net::IpAddr> {
@@ -130,6 +807,101 @@ impl Cidr {
         }
     }
 
+    #[inline]
+    ///Returns offset of `addr` from network address, which is inverse of [get](#method.get)
+    ///
+    ///Returns `None` if `addr` is not within the block or of different family
+    pub const fn index_of(&self, addr: net::IpAddr) -> Option<u128> {
+        match (self, addr) {
+            (Self::V4(cidr), net::IpAddr::V4(addr)) => match cidr.index_of(addr) {
+                Some(idx) => Some(idx as _),
+                None => None,
+            },
+            (Self::V6(cidr), net::IpAddr::V6(addr)) => cidr.index_of(addr),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns iterator over all addresses within the block
+    pub const fn iter(&self) -> CidrIter {
+        match self {
+            Self::V4(cidr) => CidrIter::V4(cidr.iter()),
+            Self::V6(cidr) => CidrIter::V6(cidr.iter()),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns iterator over usable host addresses of the block
+    ///
+    ///IPv4 block excludes network and broadcast addresses unless prefix is 31 or 32 (RFC 3021), while all addresses of IPv6 block are usable
+    pub const fn hosts(&self) -> CidrIter {
+        match self {
+            Self::V4(cidr) => CidrIter::V4(cidr.hosts()),
+            Self::V6(cidr) => CidrIter::V6(cidr.hosts()),
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over subnets of the block with `new_prefix`
+    ///
+    ///Returns `None` if `new_prefix` is less than prefix of the block or greater than address length
+    pub const fn subnets(&self, new_prefix: u8) -> Option<SubnetIter> {
+        match self {
+            Self::V4(cidr) => match cidr.subnets(new_prefix) {
+                Some(iter) => Some(SubnetIter::V4(iter)),
+                None => None,
+            },
+            Self::V6(cidr) => match cidr.subnets(new_prefix) {
+                Some(iter) => Some(SubnetIter::V6(iter)),
+                None => None,
+            },
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of usable host addresses of the block
+    ///
+    ///IPv4 block excludes network and broadcast addresses unless prefix is 31 or 32 (RFC 3021), while all addresses of IPv6 block are usable
+    pub const fn host_count(&self) -> u128 {
+        match self {
+            Self::V4(cidr) => cidr.host_count() as _,
+            Self::V6(cidr) => cidr.host_count(),
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over at most `max` first subnets of the block with `new_prefix`
+    ///
+    ///Same as [subnets](#method.subnets), but bounded, allowing to safely preview huge splits (e.g. `/32` into `/64`)
+    ///
+    ///Returns `None` if `new_prefix` is less than prefix of the block or greater than address length
+    pub fn subnets_bounded(&self, new_prefix: u8, max: usize) -> Option<core::iter::Take<SubnetIter>> {
+        match self.subnets(new_prefix) {
+            Some(iter) => Some(iter.take(max)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over `count` equal subnets of the block
+    ///
+    ///`count` is rounded up to the next power of two, hence dividing `/24` into 3 yields 4 `/26` subnets.
+    ///
+    ///Returns `None` if `count` is zero or exceeds number of addresses within the block
+    pub const fn divide_into(&self, count: u32) -> Option<SubnetIter> {
+        match self {
+            Self::V4(cidr) => match cidr.divide_into(count) {
+                Some(iter) => Some(SubnetIter::V4(iter)),
+                None => None,
+            },
+            Self::V6(cidr) => match cidr.divide_into(count) {
+                Some(iter) => Some(SubnetIter::V6(iter)),
+                None => None,
+            },
+        }
+    }
+
     #[inline(always)]
     ///Returns address corresponding `idx` without checking size according to the prefix
     ///
@@ -141,6 +913,74 @@ impl Cidr {
             Self::V6(cidr) => net::IpAddr::V6(cidr.get_unchecked(idx)),
         }
     }
+
+    #[inline]
+    ///Returns key `(family, network address, prefix)` to order blocks by network address instead of `Ord`
+    ///
+    ///Family is `4` for IPv4 and `6` for IPv6.
+    ///`Ord` places IPv4 blocks first too, but then compares prefix before address, hence `/8` sorts before any `/16`.
+    ///Key ignores host bits, so blocks with the same network and prefix have equal keys.
+    pub const fn sort_key(&self) -> (u8, u128, u8) {
+        match self {
+            Self::V4(cidr) => (4, cidr.network_addr().to_bits() as _, cidr.prefix()),
+            Self::V6(cidr) => (6, cidr.network_addr().to_bits(), cidr.prefix()),
+        }
+    }
+
+    ///Returns [summary](struct.BlockSummary.html) of the block
+    pub const fn summary(&self) -> BlockSummary {
+        match self {
+            Self::V4(cidr) => {
+                let network = cidr.network_addr();
+                let broadcast = cidr.broadcast_addr();
+                let (first_host, last_host) = match cidr.prefix() {
+                    32 | 31 => (network, broadcast),
+                    _ => (
+                        net::Ipv4Addr::from_bits(network.to_bits() + 1),
+                        net::Ipv4Addr::from_bits(broadcast.to_bits() - 1),
+                    ),
+                };
+                BlockSummary {
+                    network: net::IpAddr::V4(network),
+                    broadcast: net::IpAddr::V4(broadcast),
+                    first_host: net::IpAddr::V4(first_host),
+                    last_host: net::IpAddr::V4(last_host),
+                    usable_hosts: cidr.host_count() as _,
+                    size: cidr.size() as _,
+                    netmask: net::IpAddr::V4(cidr.netmask()),
+                    wildcard: net::IpAddr::V4(cidr.wildcard_mask()),
+                }
+            },
+            Self::V6(cidr) => BlockSummary {
+                network: net::IpAddr::V6(cidr.network_addr()),
+                broadcast: net::IpAddr::V6(cidr.broadcast_addr()),
+                first_host: net::IpAddr::V6(cidr.network_addr()),
+                last_host: net::IpAddr::V6(cidr.broadcast_addr()),
+                usable_hosts: cidr.host_count(),
+                size: cidr.size(),
+                netmask: net::IpAddr::V6(cidr.netmask()),
+                wildcard: net::IpAddr::V6(cidr.wildcard_mask()),
+            },
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    ///Returns textual representation of the block with host bits cleared (e.g. `2001:db8::/32` for `2001:db8::5/32`)
+    ///
+    ///Same as alternate form of `Display` (`{:#}`)
+    pub fn to_canonical_string(&self) -> alloc::string::String {
+        alloc::format!("{self:#}")
+    }
+
+    #[inline(always)]
+    ///Returns [Display](https://doc.rust-lang.org/core/fmt/trait.Display.html) implementation writing `self` according to `style`
+    pub const fn display_style(&self, style: DisplayStyle) -> CidrDisplay {
+        CidrDisplay {
+            cidr: *self,
+            style,
+        }
+    }
 }
 
 impl fmt::Display for Cidr {
@@ -153,6 +993,66 @@ impl fmt::Display for Cidr {
     }
 }
 
+impl TryFrom<(net::IpAddr, u8)> for Cidr {
+    type Error = PrefixError;
+
+    #[inline(always)]
+    fn try_from((addr, prefix): (net::IpAddr, u8)) -> Result<Self, Self::Error> {
+        Self::try_new(addr, prefix)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Cidr {
+    type Error = ParseError<'a>;
+
+    #[inline(always)]
+    ///Parses CIDR the same way as [parse_cidr_exact](fn.parse_cidr_exact.html)
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        parse_cidr_exact(text)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for Cidr {
+    type Err = OwnedParseError;
+
+    #[inline]
+    ///Parses CIDR the same way as [parse_cidr_exact](fn.parse_cidr_exact.html)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_cidr_exact(text).map_err(OwnedParseError::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for v4::Cidr {
+    type Err = OwnedParseError;
+
+    #[inline]
+    ///Parses CIDR the same way as [parse_cidr_family](fn.parse_cidr_family.html), requiring IPv4 address
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_cidr_family(text, AddrFamily::V4) {
+            Ok(Cidr::V4(cidr)) => Ok(cidr),
+            Ok(Cidr::V6(_)) => Err(OwnedParseError::FamilyMismatch(AddrFamily::V4)),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for v6::Cidr {
+    type Err = OwnedParseError;
+
+    #[inline]
+    ///Parses CIDR the same way as [parse_cidr_family](fn.parse_cidr_family.html), requiring IPv6 address
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_cidr_family(text, AddrFamily::V6) {
+            Ok(Cidr::V6(cidr)) => Ok(cidr),
+            Ok(Cidr::V4(_)) => Err(OwnedParseError::FamilyMismatch(AddrFamily::V6)),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
 #[inline]
 ///Parses [Cidr](enum.Cidr.html) from the input `text`
 ///
@@ -172,3 +1072,190 @@ pub const fn parse_cidr(text: &str) -> Result<Option<Cidr>, parser::ParseError<'
         Err(error) => Err(error)
     }
 }
+
+#[inline]
+///Parses [Cidr](enum.Cidr.html) from the input `text` the same way as [parse_cidr](fn.parse_cidr.html), ignoring leading and trailing ASCII whitespace
+///
+///Whitespace within CIDR is still an error, with positions relative to the start of `text`
+pub const fn parse_cidr_trimmed(text: &str) -> Result<Option<Cidr>, parser::ParseError<'_>> {
+    let trimmed = text.trim_ascii_start();
+    let offset = text.len() - trimmed.len();
+    match parse_cidr(trimmed.trim_ascii_end()) {
+        Ok(cidr) => Ok(cidr),
+        Err(error) => Err(error.offset_by(offset)),
+    }
+}
+
+#[inline]
+///Parses [Cidr](enum.Cidr.html) from the input `text`, which must contain exactly one CIDR
+///
+///Unlike [parse_cidr](fn.parse_cidr.html), prefix overflow is reported as error.
+///
+///If `prefix` is missing, prefix is assumed to be only for single IP:
+///- In case of IPv4 it means prefix is assumed to be 32
+///- In case of IPv6 it means prefix is assumed to be 128
+pub const fn parse_cidr_exact(text: &str) -> Result<Cidr, parser::ParseError<'_>> {
+    match parse_ip(text) {
+        Ok((net::IpAddr::V4(addr), None)) => Ok(Cidr::V4(v4::Cidr::new_single(addr))),
+        Ok((net::IpAddr::V4(addr), Some(prefix))) => match Cidr::new_v4(addr, prefix) {
+            Some(cidr) => Ok(cidr),
+            None => Err(ParseError::Ipv4CidrPrefixOverflow(prefix)),
+        },
+        Ok((net::IpAddr::V6(addr), None)) => Ok(Cidr::V6(v6::Cidr::new_single(addr))),
+        Ok((net::IpAddr::V6(addr), Some(prefix))) => match Cidr::new_v6(addr, prefix) {
+            Some(cidr) => Ok(cidr),
+            None => Err(ParseError::Ipv6CidrPrefixOverflow(prefix)),
+        },
+        Err(error) => Err(error)
+    }
+}
+
+#[inline]
+///Parses [Cidr](enum.Cidr.html) from the input `text` the same way as [parse_cidr_exact](fn.parse_cidr_exact.html), requiring address to be of `family`
+///
+///Returns `Err(ParseError::FamilyMismatch(family))` if `text` is valid CIDR of the other family
+pub const fn parse_cidr_family(text: &str, family: AddrFamily) -> Result<Cidr, parser::ParseError<'_>> {
+    match parse_cidr_exact(text) {
+        Ok(cidr) => match (cidr, family) {
+            (Cidr::V4(_), AddrFamily::V4) | (Cidr::V6(_), AddrFamily::V6) => Ok(cidr),
+            _ => Err(ParseError::FamilyMismatch(family)),
+        },
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Parses list of [Cidr](enum.Cidr.html) separated by commas and/or ASCII whitespace (e.g. `10.0.0.0/8, 192.168.0.0/16`)
+///
+///Each entry is parsed with [parse_cidr_exact](fn.parse_cidr_exact.html), while empty entries are skipped.
+///
+///Returns error of the first invalid entry, with positions relative to the start of `text`
+pub fn parse_cidr_list(text: &str) -> Result<alloc::vec::Vec<Cidr>, parser::ParseError<'_>> {
+    let mut result = alloc::vec::Vec::new();
+    for token in text.split(|ch: char| ch == ',' || ch.is_ascii_whitespace()) {
+        if token.is_empty() {
+            continue;
+        }
+
+        match parse_cidr_exact(token) {
+            Ok(cidr) => result.push(cidr),
+            Err(error) => return Err(error.offset_by(token.as_ptr() as usize - text.as_ptr() as usize)),
+        }
+    }
+    Ok(result)
+}
+
+#[inline]
+///Checks whether `addr` is contained within CIDR parsed from `cidr_text`
+///
+///Returns `Ok(false)` if family of `addr` doesn't match CIDR, and `Err` if `cidr_text` is not valid CIDR
+pub const fn addr_in_cidr_str(addr: net::IpAddr, cidr_text: &str) -> Result<bool, parser::ParseError<'_>> {
+    match parse_cidr_exact(cidr_text) {
+        Ok(cidr) => Ok(cidr.contains(addr)),
+        Err(error) => Err(error),
+    }
+}
+
+#[inline]
+///Checks whether `addr` is contained within any of `blocks`
+///
+///Being `const fn` it allows to check membership against list of blocks known at compile time
+pub const fn contains_any_const(blocks: &[Cidr], addr: net::IpAddr) -> bool {
+    let mut idx = 0;
+    while idx < blocks.len() {
+        if blocks[idx].contains(addr) {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+#[inline]
+///Returns number of addresses between `left` and `right`, i.e. absolute difference of their bits
+///
+///Returns `None` if addresses are of different families
+pub const fn address_distance(left: net::IpAddr, right: net::IpAddr) -> Option<u128> {
+    match (left, right) {
+        (net::IpAddr::V4(left), net::IpAddr::V4(right)) => Some(left.to_bits().abs_diff(right.to_bits()) as _),
+        (net::IpAddr::V6(left), net::IpAddr::V6(right)) => Some(left.to_bits().abs_diff(right.to_bits())),
+        _ => None,
+    }
+}
+
+///Special-purpose address blocks registered by IANA (RFC 6890 and its updates)
+pub const SPECIAL_PURPOSE: &[Cidr] = &[
+    cidr!("0.0.0.0/8"),
+    cidr!("10.0.0.0/8"),
+    cidr!("100.64.0.0/10"),
+    cidr!("127.0.0.0/8"),
+    cidr!("169.254.0.0/16"),
+    cidr!("172.16.0.0/12"),
+    cidr!("192.0.0.0/24"),
+    cidr!("192.0.2.0/24"),
+    cidr!("192.88.99.0/24"),
+    cidr!("192.168.0.0/16"),
+    cidr!("198.18.0.0/15"),
+    cidr!("198.51.100.0/24"),
+    cidr!("203.0.113.0/24"),
+    cidr!("240.0.0.0/4"),
+    cidr!("255.255.255.255/32"),
+    cidr!("::/128"),
+    cidr!("::1/128"),
+    cidr!("::ffff:0:0/96"),
+    cidr!("64:ff9b::/96"),
+    cidr!("100::/64"),
+    cidr!("2001::/23"),
+    cidr!("2001:db8::/32"),
+    cidr!("2002::/16"),
+    cidr!("fc00::/7"),
+    cidr!("fe80::/10"),
+];
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __parse_cidr_literal(text: &str) -> Cidr {
+    let reason = match parse_cidr(text) {
+        Ok(Some(cidr)) => return cidr,
+        Ok(None) => "prefix is greater than address length",
+        Err(ParseError::InvalidComponent(_)) => "invalid address component",
+        Err(ParseError::InvalidCidr(_)) => "invalid prefix",
+        Err(ParseError::UnexpectedCharacter(_, _)) => "unexpected character",
+        Err(ParseError::InvalidIp) => "input is not valid IP",
+        Err(ParseError::InvalidIpv4) => "address is not valid IPv4",
+        Err(ParseError::Ipv4InvalidComponentSize(_, _)) => "IPv4 address must have 4 components",
+        Err(ParseError::InvalidIpv6) => "address is not valid IPv6",
+        Err(ParseError::Ipv6InvalidComponentSize(_)) => "IPv6 address must have 8 components",
+        Err(ParseError::Ipv6MultipleZeroAbbrv) => "IPv6 contains more than 1 zero abbreviation",
+        Err(ParseError::TrailingWhitespace(_)) => "trailing whitespace",
+        Err(ParseError::NonAsciiCharacter(_)) => "non-ASCII character",
+        Err(ParseError::MissingIp) => "address is not specified",
+        Err(ParseError::MissingCidr) => "prefix is not specified",
+        Err(ParseError::Ipv4CidrPrefixOverflow(_)) => "prefix is greater than 32",
+        Err(ParseError::Ipv6CidrPrefixOverflow(_)) => "prefix is greater than 128",
+        Err(ParseError::FamilyMismatch(_)) => "unexpected address family",
+        Err(ParseError::NonContiguousNetmask(_)) => "netmask is not contiguous",
+        Err(ParseError::InvalidZone) => "IPv6 zone is empty",
+    };
+    panic!("{}", reason)
+}
+
+#[macro_export]
+///Parses [Cidr](enum.Cidr.html) from string literal at compile time
+///
+///Invalid literal fails compilation with the reason of parse error.
+///
+///```
+///const TRUSTED: ip_cidr::Cidr = ip_cidr::cidr!("10.0.0.0/8");
+///assert_eq!(TRUSTED.prefix(), 8);
+///```
+///
+///```compile_fail
+///const TRUSTED: ip_cidr::Cidr = ip_cidr::cidr!("10.0.0.256/8");
+///```
+macro_rules! cidr {
+    ($text:literal) => {{
+        const CIDR: $crate::Cidr = $crate::__parse_cidr_literal($text);
+        CIDR
+    }};
+}
@@ -0,0 +1,150 @@
+use core::net;
+use alloc::vec::Vec;
+
+use crate::Cidr;
+use crate::set::Trie;
+
+#[derive(Clone, Debug)]
+//Trie with values of blocks indexed by their nodes
+struct ValueTrie<V> {
+    trie: Trie,
+    values: Vec<Option<V>>,
+}
+
+impl<V> ValueTrie<V> {
+    #[inline]
+    const fn new(bits_len: u8) -> Self {
+        Self {
+            trie: Trie::new(bits_len),
+            values: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, bits: u128, prefix: u8, value: V) -> Option<V> {
+        let (idx, _) = self.trie.insert(bits, prefix);
+        if self.values.len() < self.trie.node_count() {
+            self.values.resize_with(self.trie.node_count(), || None);
+        }
+        self.values[idx].replace(value)
+    }
+
+    #[inline]
+    fn get(&self, bits: u128, prefix: u8) -> Option<&V> {
+        match self.trie.find(bits, prefix) {
+            Some(idx) => self.values[idx].as_ref(),
+            None => None,
+        }
+    }
+
+    fn longest_match(&self, bits: u128) -> Option<(u8, &V)> {
+        let mut result = None;
+        self.trie.for_each_match(bits, |prefix, idx| result = Some((prefix, idx)));
+        match result {
+            Some((prefix, idx)) => self.values[idx].as_ref().map(|value| (prefix, value)),
+            None => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Map of [Cidr](enum.Cidr.html) blocks to values, allowing to lookup value of the most specific block containing address
+///
+///Blocks are stored in prefix tries, separate for each family, with host bits cleared.
+///Lookup takes time proportional to address length regardless of number of blocks.
+pub struct CidrMap<V> {
+    v4: ValueTrie<V>,
+    v6: ValueTrie<V>,
+    len: usize,
+}
+
+impl<V> CidrMap<V> {
+    #[inline]
+    ///Creates empty map
+    pub const fn new() -> Self {
+        Self {
+            v4: ValueTrie::new(Cidr::IPV4_BITS),
+            v6: ValueTrie::new(Cidr::IPV6_BITS),
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of distinct blocks within the map
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether map has no blocks
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Associates `value` with `cidr`
+    ///
+    ///Returns previous value if block with the same network address and prefix is already present
+    pub fn insert(&mut self, cidr: Cidr, value: V) -> Option<V> {
+        let previous = match cidr {
+            Cidr::V4(cidr) => self.v4.insert(cidr.network_addr().to_bits() as _, cidr.prefix(), value),
+            Cidr::V6(cidr) => self.v6.insert(cidr.network_addr().to_bits(), cidr.prefix(), value),
+        };
+        self.len += previous.is_none() as usize;
+        previous
+    }
+
+    ///Returns value of the block with the same network address and prefix as `cidr`
+    ///
+    ///Unlike [lookup](#method.lookup), blocks containing `cidr` are not considered
+    pub fn get_exact(&self, cidr: &Cidr) -> Option<&V> {
+        match cidr {
+            Cidr::V4(cidr) => self.v4.get(cidr.network_addr().to_bits() as _, cidr.prefix()),
+            Cidr::V6(cidr) => self.v6.get(cidr.network_addr().to_bits(), cidr.prefix()),
+        }
+    }
+
+    ///Returns the most specific block of the map containing `addr`, together with its value
+    pub fn longest_match(&self, addr: net::IpAddr) -> Option<(Cidr, &V)> {
+        let (prefix, value) = match addr {
+            net::IpAddr::V4(addr) => self.v4.longest_match(addr.to_bits() as _),
+            net::IpAddr::V6(addr) => self.v6.longest_match(addr.to_bits()),
+        }?;
+        Cidr::at_prefix(addr, prefix).map(|cidr| (cidr, value))
+    }
+
+    #[inline]
+    ///Returns value of the most specific block of the map containing `addr`
+    ///
+    ///This is value counterpart of [CidrSet::longest_match](struct.CidrSet.html#method.longest_match),
+    ///so that more specific block shadows values of blocks containing it.
+    pub fn lookup(&self, addr: net::IpAddr) -> Option<&V> {
+        match addr {
+            net::IpAddr::V4(addr) => self.v4.longest_match(addr.to_bits() as _),
+            net::IpAddr::V6(addr) => self.v6.longest_match(addr.to_bits()),
+        }.map(|(_, value)| value)
+    }
+}
+
+impl<V> Default for CidrMap<V> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Extend<(Cidr, V)> for CidrMap<V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (Cidr, V)>>(&mut self, iter: I) {
+        for (cidr, value) in iter {
+            self.insert(cidr, value);
+        }
+    }
+}
+
+impl<V> FromIterator<(Cidr, V)> for CidrMap<V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Cidr, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
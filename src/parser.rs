@@ -15,10 +15,22 @@ enum ParserState {
 }
 
 mod flag {
+    pub const IS_IPV6_EMBEDDED_IPV4: u8 = 0b001;
     pub const IS_IPV6_ZERO_SKIP: u8 = 0b010;
     pub const IS_IPV6_SEP_INITIAL: u8 = 0b100;
 }
 
+const fn contains_byte(text: &[u8], byte: u8) -> bool {
+    let mut idx = 0;
+    while idx < text.len() {
+        if text[idx] == byte {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
 struct Parser<'a> {
     state: ParserState,
     family: FamilyType,
@@ -28,14 +40,40 @@ struct Parser<'a> {
     //For normal IPv6 it is always 8
     components_size: u8,
     components: [u16; 8],
+    //IPv6 may end with IPv4 address (e.g. `::ffff:127.0.0.1`), which takes place of last 2 components
+    embedded_v4_size: u8,
+    embedded_v4: [u8; 4],
     zero_component_start: u8,
     start_digit_position: usize,
+    //IPv6 zone (e.g. `eth0` in `fe80::1%eth0`), which is only accepted when `allow_zone` is set
+    allow_zone: bool,
+    zone: Option<&'a str>,
     text: &'a [u8],
 }
 
 impl<'a> Parser<'a> {
     const IPV4_LEN: u8 = 4;
     const IPV6_LEN: u8 = 8;
+    //Max number of digits within single component
+    const IPV4_COMPONENT_LEN: usize = 3;
+    const IPV6_COMPONENT_LEN: usize = 4;
+
+    const fn new(text: &'a str) -> Self {
+        Self {
+            state: ParserState::Initial,
+            flags: 0,
+            family: FamilyType::Unknown,
+            components_size: 0,
+            components: [0; 8],
+            embedded_v4_size: 0,
+            embedded_v4: [0; 4],
+            zero_component_start: 0,
+            start_digit_position: 0,
+            allow_zone: false,
+            zone: None,
+            text: text.as_bytes(),
+        }
+    }
 
     #[inline(always)]
     const fn get_current_component(&self, component_sep_pos: usize) -> &'a str {
@@ -49,7 +87,10 @@ impl<'a> Parser<'a> {
     const fn extract_v4_component(&mut self, component_sep_pos: usize) -> Option<ParseError<'a>> {
         let text = self.get_current_component(component_sep_pos);
         if self.components_size >= Self::IPV4_LEN {
-            return Some(ParseError::Ipv4InvalidComponentSize(self.components_size.saturating_add(1)));
+            //Point at separator preceding extra component
+            return Some(ParseError::Ipv4InvalidComponentSize(self.components_size.saturating_add(1), self.start_digit_position.saturating_sub(1)));
+        } else if text.len() > Self::IPV4_COMPONENT_LEN {
+            return Some(ParseError::InvalidComponent(text));
         }
 
         match u8::from_str_radix(text, 10) {
@@ -67,6 +108,8 @@ impl<'a> Parser<'a> {
         let text = self.get_current_component(component_sep_pos);
         if self.components_size >= Self::IPV6_LEN {
             return Some(ParseError::Ipv6InvalidComponentSize(self.components_size.saturating_add(1)));
+        } else if text.len() > Self::IPV6_COMPONENT_LEN {
+            return Some(ParseError::InvalidComponent(text));
         }
 
         match u16::from_str_radix(text, 16) {
@@ -80,6 +123,28 @@ impl<'a> Parser<'a> {
         }
     }
 
+    const fn extract_embedded_v4_component(&mut self, component_sep_pos: usize) -> Option<ParseError<'a>> {
+        let text = self.get_current_component(component_sep_pos);
+        if self.components_size > Self::IPV6_LEN.saturating_sub(2) {
+            return Some(ParseError::Ipv6InvalidComponentSize(self.components_size.saturating_add(2)));
+        } else if self.embedded_v4_size >= Self::IPV4_LEN {
+            return Some(ParseError::Ipv4InvalidComponentSize(self.embedded_v4_size.saturating_add(1), self.start_digit_position.saturating_sub(1)));
+        } else if text.len() > Self::IPV4_COMPONENT_LEN {
+            return Some(ParseError::InvalidComponent(text));
+        }
+
+        match u8::from_str_radix(text, 10) {
+            Ok(component) => {
+                self.embedded_v4[self.embedded_v4_size as usize] = component;
+                self.embedded_v4_size = self.embedded_v4_size.saturating_add(1);
+                self.flags |= flag::IS_IPV6_EMBEDDED_IPV4;
+                self.start_digit_position = 0;
+                None
+            },
+            Err(_) => Some(ParseError::InvalidComponent(text)),
+        }
+    }
+
     const fn read_ip_at_last(&mut self, component_sep_pos: usize) -> Result<net::IpAddr, ParseError<'a>> {
         match self.family {
             FamilyType::V4 => {
@@ -99,9 +164,25 @@ impl<'a> Parser<'a> {
                         )
                     )
                 } else {
-                    return Err(ParseError::Ipv4InvalidComponentSize(self.components_size));
+                    return Err(ParseError::Ipv4InvalidComponentSize(self.components_size, component_sep_pos));
                 }
             }
+            FamilyType::V6 if self.flags & flag::IS_IPV6_EMBEDDED_IPV4 == flag::IS_IPV6_EMBEDDED_IPV4 => {
+                if let Some(error) = self.extract_embedded_v4_component(component_sep_pos) {
+                    return Err(error)
+                }
+
+                if self.embedded_v4_size != Self::IPV4_LEN {
+                    return Err(ParseError::Ipv4InvalidComponentSize(self.embedded_v4_size, component_sep_pos));
+                }
+
+                let [a, b, c, d] = self.embedded_v4;
+                self.components[self.components_size as usize] = u16::from_be_bytes([a, b]);
+                self.components[self.components_size as usize + 1] = u16::from_be_bytes([c, d]);
+                self.components_size = self.components_size.saturating_add(2);
+
+                self.read_ipv6()
+            }
             FamilyType::V6 => {
                 if let Some(error) = self.extract_v6_component(component_sep_pos) {
                     return Err(error)
@@ -119,6 +200,9 @@ impl<'a> Parser<'a> {
     const fn read_ipv6(&mut self) -> Result<net::IpAddr, ParseError<'a>> {
         if self.components_size > Self::IPV6_LEN {
             Err(ParseError::InvalidIpv6)
+        //Zero abbreviation must replace at least 1 component
+        } else if self.components_size == Self::IPV6_LEN && self.flags & flag::IS_IPV6_ZERO_SKIP == flag::IS_IPV6_ZERO_SKIP {
+            Err(ParseError::InvalidIpv6)
         } else {
             if self.components_size < Self::IPV6_LEN {
                 if self.flags & flag::IS_IPV6_ZERO_SKIP == flag::IS_IPV6_ZERO_SKIP {
@@ -166,7 +250,7 @@ impl<'a> Parser<'a> {
     const fn on_v4_sep(&mut self, pos: usize) -> Option<ParseError<'a>> {
         let result = match self.state {
             ParserState::Digit => match self.family {
-                FamilyType::V6 => return Some(ParseError::InvalidIpv6),
+                FamilyType::V6 => self.extract_embedded_v4_component(pos),
                 FamilyType::Unknown => {
                     self.family = FamilyType::V4;
                     self.extract_v4_component(pos)
@@ -181,6 +265,11 @@ impl<'a> Parser<'a> {
 
     #[inline(always)]
     const fn on_v6_sep(&mut self, pos: usize) -> Option<ParseError<'a>> {
+        //Embedded IPv4 must be the last part of IPv6
+        if self.flags & flag::IS_IPV6_EMBEDDED_IPV4 == flag::IS_IPV6_EMBEDDED_IPV4 {
+            return Some(ParseError::InvalidIpv6);
+        }
+
         let result = match self.state {
             ParserState::Digit => match self.family {
                 FamilyType::V4 => return Some(ParseError::InvalidIpv4),
@@ -213,12 +302,22 @@ impl<'a> Parser<'a> {
         result
     }
 
+    //Returns start of whitespace at the end of text, but not before `from`
+    const fn trailing_whitespace_start(&self, from: usize) -> usize {
+        let mut idx = self.text.len();
+        while idx > from && self.text[idx - 1].is_ascii_whitespace() {
+            idx = idx - 1;
+        }
+        idx
+    }
+
     //Handles last address component if any
     const fn on_ip_end(&mut self, last_pos: usize) -> Result<net::IpAddr, ParseError<'a>> {
         match self.state {
             ParserState::Digit => self.read_ip_at_last(last_pos),
             ParserState::V4Sep => Err(ParseError::InvalidIpv4),
-            ParserState::V6Sep if self.flags & flag::IS_IPV6_ZERO_SKIP == flag::IS_IPV6_ZERO_SKIP => {
+            //Address can only end with zero abbreviation, not single separator
+            ParserState::V6Sep if self.flags & flag::IS_IPV6_ZERO_SKIP == flag::IS_IPV6_ZERO_SKIP && self.zero_component_start == self.components_size => {
                 if self.components_size == 0 {
                     Ok(net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED))
                 } else {
@@ -243,7 +342,19 @@ impl<'a> Parser<'a> {
             )
         };
 
-        match u8::from_str_radix(text, 10) {
+        //from_str_radix accepts leading sign
+        if !self.text[digit_pos].is_ascii_digit() {
+            return Err(ParseError::InvalidCidr(text));
+        }
+
+        let end = self.trailing_whitespace_start(digit_pos);
+        let prefix_text = unsafe {
+            core::str::from_utf8_unchecked(
+                slice::from_raw_parts(self.text.as_ptr().add(digit_pos), end.saturating_sub(digit_pos))
+            )
+        };
+
+        let result = match u8::from_str_radix(prefix_text, 10) {
             Ok(result) => match self.family {
                 FamilyType::V4 => {
                     if result > crate::v4::BITS_LEN {
@@ -261,7 +372,58 @@ impl<'a> Parser<'a> {
                 },
                 FamilyType::Unknown => Err(ParseError::InvalidCidr(text))
             }
+            //IPv4 allows dotted netmask (e.g. `255.255.255.0`) in place of prefix
+            Err(_) if matches!(self.family, FamilyType::V4) && contains_byte(prefix_text.as_bytes(), b'.') => match Parser::new(prefix_text).parse() {
+                Ok((net::IpAddr::V4(netmask), None)) => match crate::v4::prefix_from_netmask(netmask) {
+                    Some(prefix) => Ok(prefix),
+                    None => Err(ParseError::NonContiguousNetmask(netmask)),
+                },
+                _ => Err(ParseError::InvalidCidr(text)),
+            },
             Err(_) => Err(ParseError::InvalidCidr(text)),
+        };
+
+        match result {
+            Ok(_) if end < self.text.len() => Err(ParseError::TrailingWhitespace(end)),
+            result => result,
+        }
+    }
+
+    //Handles zone of IPv6 address, which continues until prefix or end of input
+    const fn on_zone_sep(&mut self, pos: usize) -> Result<Option<u8>, ParseError<'a>> {
+        let zone_pos = pos + 1;
+        let mut idx = zone_pos;
+        while idx < self.text.len() {
+            let ch = self.text[idx];
+            if ch == b'/' {
+                break;
+            } else if ch.is_ascii_whitespace() && self.trailing_whitespace_start(idx) == idx {
+                if idx == zone_pos {
+                    return Err(ParseError::InvalidZone);
+                }
+                return Err(ParseError::TrailingWhitespace(idx));
+            } else if !ch.is_ascii_graphic() || ch == b'%' {
+                return Err(ParseError::UnexpectedCharacter(ch as _, idx));
+            }
+            idx = idx + 1;
+        }
+
+        if idx == zone_pos {
+            return Err(ParseError::InvalidZone);
+        }
+        self.zone = Some(unsafe {
+            core::str::from_utf8_unchecked(
+                slice::from_raw_parts(self.text.as_ptr().add(zone_pos), idx - zone_pos)
+            )
+        });
+
+        if idx < self.text.len() {
+            match self.on_cidr_sep(idx) {
+                Ok(prefix) => Ok(Some(prefix)),
+                Err(error) => Err(error),
+            }
+        } else {
+            Ok(None)
         }
     }
 
@@ -291,6 +453,21 @@ impl<'a> Parser<'a> {
                     Ok(cidr) => return Ok((ip, Some(cidr))),
                     Err(error) => return Err(error),
                 }
+            } else if ch == b'%' && self.allow_zone && matches!(self.family, FamilyType::V6) {
+                let ip = match self.on_ip_end(idx) {
+                    Ok(extracted_ip) => extracted_ip,
+                    Err(error) => return Err(error),
+                };
+                match self.on_zone_sep(idx) {
+                    Ok(cidr) => return Ok((ip, cidr)),
+                    Err(error) => return Err(error),
+                }
+            } else if ch.is_ascii_whitespace() && self.trailing_whitespace_start(idx) == idx {
+                //Report whitespace only if it is the only problem
+                return match self.on_ip_end(idx) {
+                    Ok(_) => Err(ParseError::TrailingWhitespace(idx)),
+                    Err(error) => Err(error),
+                };
             } else if ch.is_ascii() {
                 return Err(ParseError::UnexpectedCharacter(ch as _, idx));
             } else {
@@ -321,13 +498,17 @@ pub enum ParseError<'a> {
     ///Address is not valid IPv4
     InvalidIpv4,
     ///IPv4 Address must have 4 components
-    Ipv4InvalidComponentSize(u8),
+    ///
+    ///Contains number of components with position of the extra separator or where separator is missing
+    Ipv4InvalidComponentSize(u8, usize),
     ///Address is not valid IPv6
     InvalidIpv6,
     ///IPv6 Address must have 8 components
     Ipv6InvalidComponentSize(u8),
     ///IPv6 contains more than 1 zero abbreviation
     Ipv6MultipleZeroAbbrv,
+    ///Input has trailing whitespace starting at position, but is otherwise valid
+    TrailingWhitespace(usize),
     ///Unexpected Non-ASCII character encountered
     NonAsciiCharacter(usize),
     ///IP address is not specified
@@ -338,6 +519,32 @@ pub enum ParseError<'a> {
     Ipv4CidrPrefixOverflow(u8),
     ///Prefix is greater than 128
     Ipv6CidrPrefixOverflow(u8),
+    ///Address is valid, but its family is not the expected one
+    FamilyMismatch(crate::AddrFamily),
+    ///IPv4 netmask has host bits interleaved with network bits
+    NonContiguousNetmask(net::Ipv4Addr),
+    ///IPv6 zone is empty
+    InvalidZone,
+}
+
+impl ParseError<'_> {
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    ///Converts error into [OwnedParseError](enum.OwnedParseError.html), copying borrowed fragments of input
+    pub fn into_owned(self) -> OwnedParseError {
+        self.into()
+    }
+
+    //Shifts positions of the error by `offset`, when input is part of the bigger text
+    pub(crate) const fn offset_by(self, offset: usize) -> Self {
+        match self {
+            Self::UnexpectedCharacter(ch, pos) => Self::UnexpectedCharacter(ch, pos + offset),
+            Self::Ipv4InvalidComponentSize(size, pos) => Self::Ipv4InvalidComponentSize(size, pos + offset),
+            Self::TrailingWhitespace(pos) => Self::TrailingWhitespace(pos + offset),
+            Self::NonAsciiCharacter(pos) => Self::NonAsciiCharacter(pos + offset),
+            error => error,
+        }
+    }
 }
 
 impl fmt::Display for ParseError<'_> {
@@ -347,7 +554,11 @@ impl fmt::Display for ParseError<'_> {
             Self::InvalidIp => fmt.write_str("Input is not valid IP"),
             Self::InvalidIpv4 => fmt.write_str("Address is not valid IPv4"),
             Self::InvalidIpv6 => fmt.write_str("Address is not valid IPv6"),
-            Self::Ipv4InvalidComponentSize(size) => fmt.write_fmt(format_args!("IPv4 Address has '{size}' components but expected 4")),
+            Self::Ipv4InvalidComponentSize(size, pos) => if *size < 4 {
+                fmt.write_fmt(format_args!("IPv4 Address has '{size}' components but expected 4, missing separator at idx={pos} (shorthand notation is not supported)"))
+            } else {
+                fmt.write_fmt(format_args!("IPv4 Address has '{size}' components but expected 4, extra separator at idx={pos}"))
+            },
             Self::Ipv6InvalidComponentSize(size) => fmt.write_fmt(format_args!("IPv6 Address has '{size}' components but expected 8")),
             Self::Ipv6MultipleZeroAbbrv => fmt.write_str("IPv6 contains more than 1 zero abbreviation"),
             Self::UnexpectedCharacter(ch, pos) => fmt.write_fmt(format_args!("Encountered unexpected character '{ch}' at idx={pos}")),
@@ -359,11 +570,16 @@ impl fmt::Display for ParseError<'_> {
                 fmt.write_str("Invalid address component: ")?;
                 fmt.write_str(addr)
             },
+            Self::TrailingWhitespace(pos) => fmt.write_fmt(format_args!("Encountered trailing whitespace at idx={pos}, input must be trimmed")),
             Self::NonAsciiCharacter(pos) => fmt.write_fmt(format_args!("Encountered non-ASCII character at idx={pos}")),
             Self::MissingIp => fmt.write_str("Address is not specified"),
             Self::MissingCidr => fmt.write_str("Prefix is not specified"),
             Self::Ipv4CidrPrefixOverflow(prefix) => fmt.write_fmt(format_args!("Prefix '{prefix}' is greater than 32")),
             Self::Ipv6CidrPrefixOverflow(prefix) => fmt.write_fmt(format_args!("Prefix '{prefix}' is greater than 128")),
+            Self::FamilyMismatch(crate::AddrFamily::V4) => fmt.write_str("Expected IPv4 address"),
+            Self::FamilyMismatch(crate::AddrFamily::V6) => fmt.write_str("Expected IPv6 address"),
+            Self::NonContiguousNetmask(netmask) => fmt.write_fmt(format_args!("Netmask '{netmask}' is not contiguous")),
+            Self::InvalidZone => fmt.write_str("IPv6 zone is empty"),
         }
     }
 }
@@ -371,19 +587,183 @@ impl fmt::Display for ParseError<'_> {
 impl core::error::Error for ParseError<'_> {
 }
 
+#[derive(Debug, PartialEq, Eq)]
+///Parsing error with state of the parser at the moment of failure
+pub struct VerboseParseError<'a> {
+    ///Error
+    pub error: ParseError<'a>,
+    ///Address family, if parser managed to detect it
+    pub family: Option<crate::AddrFamily>,
+    ///Number of successfully parsed components
+    pub components_size: u8,
+    ///Address components, only first `components_size` are valid
+    ///
+    ///IPv4 components are stored as is, without conversion to IPv6 representation
+    pub components: [u16; 8],
+}
+
+impl VerboseParseError<'_> {
+    #[inline(always)]
+    ///Returns successfully parsed components
+    pub fn components(&self) -> &[u16] {
+        &self.components[..self.components_size as usize]
+    }
+}
+
+impl fmt::Display for VerboseParseError<'_> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, fmt)
+    }
+}
+
+impl core::error::Error for VerboseParseError<'_> {
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Owned version of [ParseError](enum.ParseError.html), which doesn't borrow input
+pub enum OwnedParseError {
+    ///Invalid address component
+    InvalidComponent(alloc::string::String),
+    ///Invalid CIDR prefix
+    InvalidCidr(alloc::string::String),
+    ///Unexpected character with position where it is encountered at
+    UnexpectedCharacter(char, usize),
+    ///Input is not valid IP
+    InvalidIp,
+    ///Address is not valid IPv4
+    InvalidIpv4,
+    ///IPv4 Address must have 4 components
+    ///
+    ///Contains number of components with position of the extra separator or where separator is missing
+    Ipv4InvalidComponentSize(u8, usize),
+    ///Address is not valid IPv6
+    InvalidIpv6,
+    ///IPv6 Address must have 8 components
+    Ipv6InvalidComponentSize(u8),
+    ///IPv6 contains more than 1 zero abbreviation
+    Ipv6MultipleZeroAbbrv,
+    ///Input has trailing whitespace starting at position, but is otherwise valid
+    TrailingWhitespace(usize),
+    ///Unexpected Non-ASCII character encountered
+    NonAsciiCharacter(usize),
+    ///IP address is not specified
+    MissingIp,
+    ///Prefix is not specified
+    MissingCidr,
+    ///Prefix is greater than 32
+    Ipv4CidrPrefixOverflow(u8),
+    ///Prefix is greater than 128
+    Ipv6CidrPrefixOverflow(u8),
+    ///Address is valid, but its family is not the expected one
+    FamilyMismatch(crate::AddrFamily),
+    ///IPv4 netmask has host bits interleaved with network bits
+    NonContiguousNetmask(net::Ipv4Addr),
+    ///IPv6 zone is empty
+    InvalidZone,
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedParseError {
+    ///Returns borrowed [ParseError](enum.ParseError.html)
+    pub fn as_error(&self) -> ParseError<'_> {
+        match self {
+            Self::InvalidComponent(text) => ParseError::InvalidComponent(text),
+            Self::InvalidCidr(text) => ParseError::InvalidCidr(text),
+            Self::UnexpectedCharacter(ch, pos) => ParseError::UnexpectedCharacter(*ch, *pos),
+            Self::InvalidIp => ParseError::InvalidIp,
+            Self::InvalidIpv4 => ParseError::InvalidIpv4,
+            Self::Ipv4InvalidComponentSize(size, pos) => ParseError::Ipv4InvalidComponentSize(*size, *pos),
+            Self::InvalidIpv6 => ParseError::InvalidIpv6,
+            Self::Ipv6InvalidComponentSize(size) => ParseError::Ipv6InvalidComponentSize(*size),
+            Self::Ipv6MultipleZeroAbbrv => ParseError::Ipv6MultipleZeroAbbrv,
+            Self::TrailingWhitespace(pos) => ParseError::TrailingWhitespace(*pos),
+            Self::NonAsciiCharacter(pos) => ParseError::NonAsciiCharacter(*pos),
+            Self::MissingIp => ParseError::MissingIp,
+            Self::MissingCidr => ParseError::MissingCidr,
+            Self::Ipv4CidrPrefixOverflow(prefix) => ParseError::Ipv4CidrPrefixOverflow(*prefix),
+            Self::Ipv6CidrPrefixOverflow(prefix) => ParseError::Ipv6CidrPrefixOverflow(*prefix),
+            Self::FamilyMismatch(family) => ParseError::FamilyMismatch(*family),
+            Self::NonContiguousNetmask(netmask) => ParseError::NonContiguousNetmask(*netmask),
+            Self::InvalidZone => ParseError::InvalidZone,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseError<'_>> for OwnedParseError {
+    fn from(error: ParseError<'_>) -> Self {
+        match error {
+            ParseError::InvalidComponent(text) => Self::InvalidComponent(text.into()),
+            ParseError::InvalidCidr(text) => Self::InvalidCidr(text.into()),
+            ParseError::UnexpectedCharacter(ch, pos) => Self::UnexpectedCharacter(ch, pos),
+            ParseError::InvalidIp => Self::InvalidIp,
+            ParseError::InvalidIpv4 => Self::InvalidIpv4,
+            ParseError::Ipv4InvalidComponentSize(size, pos) => Self::Ipv4InvalidComponentSize(size, pos),
+            ParseError::InvalidIpv6 => Self::InvalidIpv6,
+            ParseError::Ipv6InvalidComponentSize(size) => Self::Ipv6InvalidComponentSize(size),
+            ParseError::Ipv6MultipleZeroAbbrv => Self::Ipv6MultipleZeroAbbrv,
+            ParseError::TrailingWhitespace(pos) => Self::TrailingWhitespace(pos),
+            ParseError::NonAsciiCharacter(pos) => Self::NonAsciiCharacter(pos),
+            ParseError::MissingIp => Self::MissingIp,
+            ParseError::MissingCidr => Self::MissingCidr,
+            ParseError::Ipv4CidrPrefixOverflow(prefix) => Self::Ipv4CidrPrefixOverflow(prefix),
+            ParseError::Ipv6CidrPrefixOverflow(prefix) => Self::Ipv6CidrPrefixOverflow(prefix),
+            ParseError::FamilyMismatch(family) => Self::FamilyMismatch(family),
+            ParseError::NonContiguousNetmask(netmask) => Self::NonContiguousNetmask(netmask),
+            ParseError::InvalidZone => Self::InvalidZone,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for OwnedParseError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_error(), fmt)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for OwnedParseError {
+}
+
 ///Performs parsing of the string into IP addr with optional CIDR prefix
 pub const fn parse_ip(text: &str) -> Result<(net::IpAddr, Option<u8>), ParseError<'_>> {
-    let text = text.as_bytes();
-
-    let mut parser = Parser {
-        state: ParserState::Initial,
-        flags: 0,
-        family: FamilyType::Unknown,
-        components_size: 0,
-        components: [0; 8],
-        zero_component_start: 0,
-        start_digit_position: 0,
-        text,
-    };
+    let mut parser = Parser::new(text);
     parser.parse()
 }
+
+///Performs parsing of the string into IP addr with optional zone and CIDR prefix
+///
+///Zone is only accepted for IPv6 address and follows address after `%` (e.g. `fe80::1%eth0/64`).
+///Numeric zones are returned as text too.
+pub const fn parse_ip_zoned(text: &str) -> Result<(net::IpAddr, Option<&str>, Option<u8>), ParseError<'_>> {
+    let mut parser = Parser::new(text);
+    parser.allow_zone = true;
+    match parser.parse() {
+        Ok((ip, prefix)) => Ok((ip, parser.zone, prefix)),
+        Err(error) => Err(error),
+    }
+}
+
+///Performs parsing of the string into IP addr with optional CIDR prefix
+///
+///Unlike [parse_ip](fn.parse_ip.html), on error returns partially parsed address for diagnostic purposes
+pub const fn parse_ip_verbose(text: &str) -> Result<(net::IpAddr, Option<u8>), VerboseParseError<'_>> {
+    let mut parser = Parser::new(text);
+    match parser.parse() {
+        Ok(result) => Ok(result),
+        Err(error) => Err(VerboseParseError {
+            error,
+            family: match parser.family {
+                FamilyType::Unknown => None,
+                FamilyType::V4 => Some(crate::AddrFamily::V4),
+                FamilyType::V6 => Some(crate::AddrFamily::V6),
+            },
+            components_size: parser.components_size,
+            components: parser.components,
+        }),
+    }
+}
@@ -0,0 +1,87 @@
+use core::net;
+use alloc::vec::Vec;
+
+use crate::Cidr;
+
+//Returns smallest prefix for the block that fits `hosts` usable addresses
+//
+//IPv4 blocks lose network and broadcast addresses, except for /31 and /32
+const fn host_prefix(addr: net::IpAddr, hosts: u32) -> Option<u8> {
+    let hosts = hosts as u128;
+    match addr {
+        net::IpAddr::V4(_) => match hosts {
+            0 | 1 => Some(crate::v4::BITS_LEN),
+            2 => Some(crate::v4::BITS_LEN - 1),
+            hosts => {
+                let required_bits = u128::BITS - (hosts + 1).leading_zeros();
+                if required_bits > crate::v4::BITS_LEN as u32 {
+                    None
+                } else {
+                    Some(crate::v4::BITS_LEN - required_bits as u8)
+                }
+            }
+        },
+        net::IpAddr::V6(_) => match hosts {
+            0 | 1 => Some(crate::v6::BITS_LEN),
+            hosts => {
+                let required_bits = u128::BITS - (hosts - 1).leading_zeros();
+                Some(crate::v6::BITS_LEN - required_bits as u8)
+            }
+        },
+    }
+}
+
+const fn to_bits(addr: net::IpAddr) -> u128 {
+    match addr {
+        net::IpAddr::V4(addr) => addr.to_bits() as _,
+        net::IpAddr::V6(addr) => addr.to_bits(),
+    }
+}
+
+///Splits `block` into non-overlapping subnets with enough usable addresses for each of `host_counts` (VLSM)
+///
+///Subnets are allocated largest first from the network address of the `block`, but returned in order of `host_counts`.
+///
+///Usable addresses of IPv4 subnet exclude network and broadcast addresses, unless prefix is 31 or 32.
+///All addresses of IPv6 subnet are considered usable.
+///
+///Returns `None` if subnets do not fit `block`
+pub fn plan_subnets(block: &Cidr, host_counts: &[u32]) -> Option<Vec<Cidr>> {
+    let mut prefixes = Vec::with_capacity(host_counts.len());
+    for (idx, hosts) in host_counts.iter().enumerate() {
+        match host_prefix(block.addr(), *hosts) {
+            Some(prefix) if prefix >= block.prefix() => prefixes.push((idx, prefix)),
+            _ => return None,
+        }
+    }
+    //Largest blocks first, so that every next block is aligned to its prefix
+    prefixes.sort_by_key(|(_, prefix)| *prefix);
+
+    let last = to_bits(block.broadcast_addr());
+    let mut next = Some(to_bits(block.network_addr()));
+    let mut result = Vec::with_capacity(prefixes.len());
+    for (idx, prefix) in prefixes {
+        let start = next?;
+        let host_mask = match block {
+            Cidr::V4(_) => (u32::MAX as u128) >> prefix,
+            Cidr::V6(_) => u128::MAX.checked_shr(prefix as _).unwrap_or(0),
+        };
+        let end = match start.checked_add(host_mask) {
+            Some(end) if end <= last => end,
+            _ => return None,
+        };
+        next = end.checked_add(1);
+
+        let cidr = match block {
+            Cidr::V4(_) => Cidr::new_v4(net::Ipv4Addr::from_bits(start as _), prefix),
+            Cidr::V6(_) => Cidr::new_v6(net::Ipv6Addr::from_bits(start), prefix),
+        };
+        match cidr {
+            Some(cidr) => result.push((idx, cidr)),
+            None => return None,
+        }
+    }
+
+    result.sort_by_key(|(idx, _)| *idx);
+    Some(result.into_iter().map(|(_, cidr)| cidr).collect())
+}
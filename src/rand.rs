@@ -0,0 +1,42 @@
+use core::net;
+
+use rand::distr::Distribution;
+use rand::Rng;
+
+use crate::{v4, v6, Cidr};
+
+impl Distribution<net::Ipv4Addr> for v4::Cidr {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> net::Ipv4Addr {
+        let host_bits = rng.random::<u32>() & self.wildcard_mask().to_bits();
+        net::Ipv4Addr::from_bits(self.network_addr().to_bits() | host_bits)
+    }
+}
+
+impl Distribution<net::Ipv6Addr> for v6::Cidr {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> net::Ipv6Addr {
+        let host_bits = rng.random::<u128>() & self.wildcard_mask().to_bits();
+        net::Ipv6Addr::from_bits(self.network_addr().to_bits() | host_bits)
+    }
+}
+
+impl Distribution<net::IpAddr> for Cidr {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> net::IpAddr {
+        match self {
+            Self::V4(cidr) => net::IpAddr::V4(cidr.sample(rng)),
+            Self::V6(cidr) => net::IpAddr::V6(cidr.sample(rng)),
+        }
+    }
+}
+
+impl Cidr {
+    #[inline(always)]
+    ///Returns uniformly distributed random address within the block
+    ///
+    ///Network and broadcast addresses are not excluded.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> net::IpAddr {
+        Distribution::sample(self, rng)
+    }
+}
@@ -0,0 +1,147 @@
+use core::net;
+use alloc::vec::Vec;
+
+use crate::{Cidr, RangeError};
+
+//Appends minimal blocks covering `start..=end` where `start` and `end` are addresses of the same family as `addr`
+fn push_range(addr: net::IpAddr, start: u128, end: u128, result: &mut Vec<Cidr>) {
+    for_each_range_block(addr, start, end, |cidr| result.push(cidr));
+}
+
+//Calls `f` with minimal blocks covering `start..=end` in ascending order, see `push_range`
+pub(crate) fn for_each_range_block(addr: net::IpAddr, mut start: u128, end: u128, mut f: impl FnMut(Cidr)) {
+    let bits_len = match addr {
+        net::IpAddr::V4(_) => Cidr::IPV4_BITS,
+        net::IpAddr::V6(_) => Cidr::IPV6_BITS,
+    };
+
+    loop {
+        //Block must be aligned to its size and must not go past `end`
+        let span = end - start;
+        let fit_bits = match span.checked_add(1) {
+            Some(len) => u128::BITS - 1 - len.leading_zeros(),
+            None => u128::BITS,
+        };
+        let host_bits = start.trailing_zeros().min(fit_bits).min(bits_len as u32) as u8;
+
+        let cidr = match addr {
+            net::IpAddr::V4(_) => Cidr::new_v4(net::Ipv4Addr::from_bits(start as _), bits_len - host_bits),
+            net::IpAddr::V6(_) => Cidr::new_v6(net::Ipv6Addr::from_bits(start), bits_len - host_bits),
+        };
+        match cidr {
+            Some(cidr) => f(cidr),
+            None => unreachable!(),
+        }
+
+        let host_mask = match host_bits {
+            0 => 0,
+            host_bits => u128::MAX >> (u128::BITS - host_bits as u32),
+        };
+        let last = start + host_mask;
+        if last >= end {
+            break;
+        }
+        start = last + 1;
+    }
+}
+
+//Returns first and last address of the block
+fn bounds(cidr: &Cidr) -> (u128, u128) {
+    match cidr {
+        Cidr::V4(cidr) => (cidr.network_addr().to_bits() as _, cidr.broadcast_addr().to_bits() as _),
+        Cidr::V6(cidr) => (cidr.network_addr().to_bits(), cidr.broadcast_addr().to_bits()),
+    }
+}
+
+//Appends minimal blocks covering addresses of `block` that are not within `excluded`, which must be within `block`
+fn push_excluded(block: &Cidr, excluded: &Cidr, result: &mut Vec<Cidr>) {
+    let (start, end) = bounds(block);
+    let (excluded_start, excluded_end) = bounds(excluded);
+    if start < excluded_start {
+        push_range(block.addr(), start, excluded_start - 1, result);
+    }
+    if excluded_end < end {
+        push_range(block.addr(), excluded_end + 1, end, result);
+    }
+}
+
+impl Cidr {
+    ///Returns minimal list of blocks covering addresses that are within exactly one of `self` and `other`
+    ///
+    ///Blocks are returned in ascending order with host bits cleared:
+    ///- If one block contains the other, result is difference between them;
+    ///- If blocks are disjoint, result is both blocks;
+    ///- If blocks are equal, result is empty.
+    pub fn symmetric_difference(&self, other: &Cidr) -> Vec<Cidr> {
+        let mut result = Vec::new();
+        let (this, other) = match self.sort_key() <= other.sort_key() {
+            true => (self.canonical(), other.canonical()),
+            false => (other.canonical(), self.canonical()),
+        };
+
+        if this.contains(other.addr()) {
+            push_excluded(&this, &other, &mut result);
+        } else {
+            result.push(this);
+            result.push(other);
+        }
+        result
+    }
+
+    ///Returns minimal list of blocks covering addresses of `self` that are not within `other`
+    ///
+    ///- If `other` is within `self`, result is ascending blocks with host bits cleared;
+    ///- If `other` contains `self`, result is empty;
+    ///- Otherwise blocks are disjoint and result is `self` only.
+    pub fn exclude(&self, other: &Cidr) -> Vec<Cidr> {
+        let mut result = Vec::new();
+        if other.contains_cidr(self) {
+            return result;
+        } else if self.contains_cidr(other) {
+            push_excluded(self, other, &mut result);
+        } else {
+            result.push(*self);
+        }
+        result
+    }
+}
+
+///Returns minimal list of blocks covering exactly `count` addresses starting with `start`
+///
+///Returns `Err` if `count` is zero or range goes past the highest address of the family
+pub fn cidrs_for_count(start: net::IpAddr, count: u128) -> Result<Vec<Cidr>, RangeError> {
+    if count == 0 {
+        return Err(RangeError::Empty);
+    }
+
+    let (start_bits, max) = match start {
+        net::IpAddr::V4(start) => (start.to_bits() as u128, u32::MAX as u128),
+        net::IpAddr::V6(start) => (start.to_bits(), u128::MAX),
+    };
+    let end = match start_bits.checked_add(count - 1) {
+        Some(end) if end <= max => end,
+        _ => return Err(RangeError::Overflow),
+    };
+
+    let mut result = Vec::new();
+    push_range(start, start_bits, end, &mut result);
+    Ok(result)
+}
+
+///Returns minimal list of blocks covering exactly addresses from `start` to `end` inclusive
+///
+///Returns `Err` if `end` is less than `start` or addresses are of different families
+pub fn summarize_range(start: net::IpAddr, end: net::IpAddr) -> Result<Vec<Cidr>, RangeError> {
+    let (start_bits, end_bits) = match (start, end) {
+        (net::IpAddr::V4(start), net::IpAddr::V4(end)) => (start.to_bits() as u128, end.to_bits() as u128),
+        (net::IpAddr::V6(start), net::IpAddr::V6(end)) => (start.to_bits(), end.to_bits()),
+        _ => return Err(RangeError::FamilyMismatch),
+    };
+    if end_bits < start_bits {
+        return Err(RangeError::Empty);
+    }
+
+    let mut result = Vec::new();
+    push_range(start, start_bits, end_bits, &mut result);
+    Ok(result)
+}
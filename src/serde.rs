@@ -0,0 +1,172 @@
+use core::{fmt, marker, net};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{v4, v6, AddrFamily, Cidr};
+
+//Binary representation is address octets followed by prefix
+const V4_LEN: usize = 5;
+const V6_LEN: usize = 17;
+
+trait Repr: Sized {
+    const EXPECTING: &'static str;
+
+    fn from_text(text: &str) -> Result<Self, crate::ParseError<'_>>;
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl Repr for v4::Cidr {
+    const EXPECTING: &'static str = "IPv4 CIDR";
+
+    #[inline]
+    fn from_text(text: &str) -> Result<Self, crate::ParseError<'_>> {
+        match crate::parse_cidr_family(text, AddrFamily::V4) {
+            Ok(Cidr::V4(cidr)) => Ok(cidr),
+            Ok(Cidr::V6(_)) => Err(crate::ParseError::FamilyMismatch(AddrFamily::V4)),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [a, b, c, d, prefix] => Self::new(net::Ipv4Addr::new(*a, *b, *c, *d), *prefix),
+            _ => None,
+        }
+    }
+}
+
+impl Repr for v6::Cidr {
+    const EXPECTING: &'static str = "IPv6 CIDR";
+
+    #[inline]
+    fn from_text(text: &str) -> Result<Self, crate::ParseError<'_>> {
+        match crate::parse_cidr_family(text, AddrFamily::V6) {
+            Ok(Cidr::V6(cidr)) => Ok(cidr),
+            Ok(Cidr::V4(_)) => Err(crate::ParseError::FamilyMismatch(AddrFamily::V6)),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != V6_LEN {
+            return None;
+        }
+
+        let mut octets = [0u8; V6_LEN - 1];
+        octets.copy_from_slice(&bytes[..V6_LEN - 1]);
+        Self::new(net::Ipv6Addr::from(octets), bytes[V6_LEN - 1])
+    }
+}
+
+impl Repr for Cidr {
+    const EXPECTING: &'static str = "IP CIDR";
+
+    #[inline(always)]
+    fn from_text(text: &str) -> Result<Self, crate::ParseError<'_>> {
+        crate::parse_cidr_exact(text)
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            V4_LEN => v4::Cidr::from_bytes(bytes).map(Self::V4),
+            V6_LEN => v6::Cidr::from_bytes(bytes).map(Self::V6),
+            _ => None,
+        }
+    }
+}
+
+struct CidrVisitor<T>(marker::PhantomData<T>);
+
+impl<T: Repr> de::Visitor<'_> for CidrVisitor<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(T::EXPECTING)
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        T::from_text(text).map_err(E::custom)
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        match T::from_bytes(bytes) {
+            Some(cidr) => Ok(cidr),
+            None => Err(E::invalid_value(de::Unexpected::Bytes(bytes), &self)),
+        }
+    }
+}
+
+#[inline]
+fn deserialize<'de, T: Repr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let visitor = CidrVisitor(marker::PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+impl Serialize for v4::Cidr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut bytes = [0u8; V4_LEN];
+            bytes[..V4_LEN - 1].copy_from_slice(&self.addr().octets());
+            bytes[V4_LEN - 1] = self.prefix();
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl Serialize for v6::Cidr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut bytes = [0u8; V6_LEN];
+            bytes[..V6_LEN - 1].copy_from_slice(&self.addr().octets());
+            bytes[V6_LEN - 1] = self.prefix();
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl Serialize for Cidr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::V4(cidr) => cidr.serialize(serializer),
+            Self::V6(cidr) => cidr.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for v4::Cidr {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for v6::Cidr {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cidr {
+    #[inline(always)]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
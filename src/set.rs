@@ -0,0 +1,780 @@
+use core::{fmt, net};
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+
+use crate::{aggregate_into, parse_cidr_exact, Cidr, IpRange, ParseError};
+use crate::ip_range::addr_bits;
+use crate::range::for_each_range_block;
+
+//Sample addresses of each family, in order of tries
+const FAMILY_ADDRS: [net::IpAddr; 2] = [net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED), net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED)];
+
+//Returns ranges of `left` not covered by `right`, both being sorted, disjoint and non-adjacent
+fn subtract(left: &[(u128, u128)], right: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut result = Vec::new();
+    let mut idx = 0;
+    for &(mut start, end) in left {
+        while idx < right.len() && right[idx].1 < start {
+            idx += 1;
+        }
+
+        let mut is_covered = false;
+        while let Some(&(right_start, right_end)) = right.get(idx) {
+            if right_start > end {
+                break;
+            }
+            if start < right_start {
+                result.push((start, right_start - 1));
+            }
+            if right_end >= end {
+                is_covered = true;
+                break;
+            }
+            start = right_end + 1;
+            idx += 1;
+        }
+
+        if !is_covered {
+            result.push((start, end));
+        }
+    }
+    result
+}
+
+//Header of packed set: magic, version and big endian number of entries
+const PACKED_MAGIC: [u8; 4] = *b"CIDR";
+const PACKED_VERSION: u8 = 1;
+const PACKED_HEADER_LEN: usize = PACKED_MAGIC.len() + 1 + 8;
+//Family tags of packed entries, which are followed by prefix and address octets
+const PACKED_V4: u8 = 4;
+const PACKED_V6: u8 = 6;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Error decoding [CidrSet](struct.CidrSet.html) from packed bytes
+pub enum DecodeError {
+    ///Bytes do not start with expected magic
+    InvalidMagic,
+    ///Format version is not supported
+    UnsupportedVersion(u8),
+    ///Bytes end before header or entry is complete
+    UnexpectedEnd,
+    ///Entry at specified byte offset has unknown family or invalid prefix
+    InvalidEntry(usize),
+    ///Bytes remain past the last entry
+    TrailingData,
+}
+
+impl fmt::Display for DecodeError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => fmt.write_str("Invalid magic"),
+            Self::UnsupportedVersion(version) => fmt.write_fmt(format_args!("Unsupported version {version}")),
+            Self::UnexpectedEnd => fmt.write_str("Unexpected end of data"),
+            Self::InvalidEntry(offset) => fmt.write_fmt(format_args!("Invalid entry at offset {offset}")),
+            Self::TrailingData => fmt.write_str("Trailing data after the last entry"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {
+}
+
+//Marks absent child, as root node can never be a child
+const NONE: u32 = 0;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct Node {
+    children: [u32; 2],
+    //Whether block ending at this node is stored
+    is_block: bool,
+}
+
+#[derive(Clone, Debug)]
+//Binary trie of network addresses, where depth of node is prefix of the block
+pub(crate) struct Trie {
+    bits_len: u8,
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    #[inline]
+    pub(crate) const fn new(bits_len: u8) -> Self {
+        Self {
+            bits_len,
+            nodes: Vec::new(),
+        }
+    }
+
+    #[inline(always)]
+    const fn bit(&self, bits: u128, depth: u8) -> usize {
+        ((bits >> (self.bits_len - 1 - depth)) & 1) as usize
+    }
+
+    #[inline(always)]
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    //Returns index of the block's node and whether block was not present
+    pub(crate) fn insert(&mut self, bits: u128, prefix: u8) -> (usize, bool) {
+        if self.nodes.is_empty() {
+            self.nodes.push(Node::default());
+        }
+
+        let mut idx = 0;
+        for depth in 0..prefix {
+            let bit = self.bit(bits, depth);
+            idx = match self.nodes[idx].children[bit] {
+                NONE => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[idx].children[bit] = child as u32;
+                    child
+                },
+                child => child as usize,
+            };
+        }
+
+        let is_new = !self.nodes[idx].is_block;
+        self.nodes[idx].is_block = true;
+        (idx, is_new)
+    }
+
+    //`prefix` must not exceed address length
+    fn block(&self, bits: u128, prefix: u8) -> Cidr {
+        let cidr = match self.bits_len {
+            Cidr::IPV4_BITS => Cidr::new_v4(net::Ipv4Addr::from_bits(bits as _), prefix),
+            _ => Cidr::new_v6(net::Ipv6Addr::from_bits(bits), prefix),
+        };
+        match cidr {
+            Some(cidr) => cidr,
+            None => unreachable!(),
+        }
+    }
+
+    //Returns sorted, disjoint and non-adjacent ranges of addresses covered by stored blocks
+    fn intervals(&self) -> Vec<(u128, u128)> {
+        let mut result = Vec::<(u128, u128)>::new();
+        for cidr in TrieIter::new(self) {
+            let (start, end) = (addr_bits(cidr.network_addr()), addr_bits(cidr.broadcast_addr()));
+            match result.last_mut() {
+                Some(last) if last.1 == u128::MAX || start <= last.1 + 1 => last.1 = last.1.max(end),
+                _ => result.push((start, end)),
+            }
+        }
+        result
+    }
+
+    //Returns index of the node of stored block with exactly `prefix`
+    pub(crate) fn find(&self, bits: u128, prefix: u8) -> Option<usize> {
+        let mut idx = 0;
+        for depth in 0..prefix {
+            idx = match self.nodes.get(idx)?.children[self.bit(bits, depth)] {
+                NONE => return None,
+                child => child as usize,
+            };
+        }
+
+        match self.nodes.get(idx) {
+            Some(node) if node.is_block => Some(idx),
+            _ => None,
+        }
+    }
+
+    //Calls `f` with prefix and node index of each stored block containing `bits`, from the smallest prefix
+    pub(crate) fn for_each_match(&self, bits: u128, mut f: impl FnMut(u8, usize)) {
+        let mut idx = 0;
+        let mut depth = 0;
+        while let Some(node) = self.nodes.get(idx) {
+            if node.is_block {
+                f(depth, idx);
+            }
+            if depth == self.bits_len {
+                break;
+            }
+
+            idx = match node.children[self.bit(bits, depth)] {
+                NONE => break,
+                child => child as usize,
+            };
+            depth += 1;
+        }
+    }
+
+    //Returns smallest prefix not greater than `prefix`, at which block of `bits` does not overlap any stored block
+    fn disjoint_prefix(&self, bits: u128, prefix: u8) -> Option<u8> {
+        let mut idx = 0;
+        let mut depth = 0;
+        //Nodes only exist on the path to stored blocks, hence block overlaps set when it contains any node
+        while let Some(node) = self.nodes.get(idx) {
+            if node.is_block || depth == prefix {
+                return None;
+            }
+
+            idx = match node.children[self.bit(bits, depth)] {
+                NONE => return Some(depth + 1),
+                child => child as usize,
+            };
+            depth += 1;
+        }
+        Some(0)
+    }
+
+    #[inline]
+    //Returns largest prefix of stored block containing `bits`
+    fn longest_match(&self, bits: u128) -> Option<u8> {
+        let mut result = None;
+        self.for_each_match(bits, |prefix, _| result = Some(prefix));
+        result
+    }
+}
+
+#[derive(Clone, Debug)]
+//Depth-first walk visiting lower half before upper half, hence blocks are yielded in ascending order
+struct TrieIter<'a> {
+    trie: &'a Trie,
+    //Node index, network bits and depth of nodes yet to be visited
+    stack: Vec<(u32, u128, u8)>,
+}
+
+impl<'a> TrieIter<'a> {
+    #[inline]
+    fn new(trie: &'a Trie) -> Self {
+        let mut stack = Vec::new();
+        if !trie.nodes.is_empty() {
+            stack.push((0, 0, 0));
+        }
+        Self {
+            trie,
+            stack,
+        }
+    }
+}
+
+impl Iterator for TrieIter<'_> {
+    type Item = Cidr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((idx, bits, depth)) = self.stack.pop() {
+            let node = &self.trie.nodes[idx as usize];
+            let [low, high] = node.children;
+            if high != NONE {
+                self.stack.push((high, bits | 1 << (self.trie.bits_len - 1 - depth), depth + 1));
+            }
+            if low != NONE {
+                self.stack.push((low, bits, depth + 1));
+            }
+
+            if node.is_block {
+                return Some(self.trie.block(bits, depth));
+            }
+        }
+        None
+    }
+}
+
+#[derive(Clone, Debug)]
+///Iterator over blocks of [CidrSet](struct.CidrSet.html)
+///
+///Blocks are yielded with IPv4 blocks first, then by network address, with shorter prefix first.
+pub struct CidrSetIter<'a> {
+    v4: TrieIter<'a>,
+    v6: TrieIter<'a>,
+}
+
+impl Iterator for CidrSetIter<'_> {
+    type Item = Cidr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.v4.next() {
+            Some(cidr) => Some(cidr),
+            None => self.v6.next(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+///Iterator over blocks of [CidrSet](struct.CidrSet.html) containing address, from the most specific block
+pub struct MatchIter {
+    addr: net::IpAddr,
+    //Ascending prefixes of matching blocks
+    prefixes: Vec<u8>,
+}
+
+impl Iterator for MatchIter {
+    type Item = Cidr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let prefix = self.prefixes.pop()?;
+        Cidr::at_prefix(self.addr, prefix)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.prefixes.len(), Some(self.prefixes.len()))
+    }
+}
+
+impl ExactSizeIterator for MatchIter {
+}
+
+#[derive(Clone, Debug)]
+///Iterator over first and last addresses of collapsed blocks of [CidrSet](struct.CidrSet.html)
+pub struct RangeIter {
+    blocks: alloc::vec::IntoIter<Cidr>,
+}
+
+impl Iterator for RangeIter {
+    type Item = (net::IpAddr, net::IpAddr);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.blocks.next().map(|cidr| (cidr.network_addr(), cidr.broadcast_addr()))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.blocks.size_hint()
+    }
+}
+
+impl ExactSizeIterator for RangeIter {
+}
+
+#[derive(Clone, Debug)]
+///Set of [Cidr](enum.Cidr.html) blocks, allowing to lookup address in time proportional to address length
+///
+///Blocks are stored in prefix tries, separate for each family, with host bits cleared.
+pub struct CidrSet {
+    v4: Trie,
+    v6: Trie,
+    len: usize,
+}
+
+impl CidrSet {
+    #[inline]
+    ///Creates empty set
+    pub const fn new() -> Self {
+        Self {
+            v4: Trie::new(Cidr::IPV4_BITS),
+            v6: Trie::new(Cidr::IPV6_BITS),
+            len: 0,
+        }
+    }
+
+    ///Creates set out of minimal blocks covering `ranges`
+    ///
+    ///Blocks of all ranges are aggregated together, hence adjacent and overlapping ranges are merged.
+    pub fn from_ranges<I: IntoIterator<Item = IpRange>>(ranges: I) -> Self {
+        let mut builder = CidrSetBuilder::new();
+        for range in ranges {
+            builder.add_range(range);
+        }
+        builder.build()
+    }
+
+    #[inline(always)]
+    ///Returns number of distinct blocks within the set
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether set has no blocks
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    ///Returns iterator over blocks of the set in ascending order
+    pub fn iter(&self) -> CidrSetIter<'_> {
+        CidrSetIter {
+            v4: TrieIter::new(&self.v4),
+            v6: TrieIter::new(&self.v6),
+        }
+    }
+
+    //Returns smallest equivalent list of blocks, see `aggregate`
+    fn collapsed(&self) -> Vec<Cidr> {
+        let mut blocks = self.iter().collect::<Vec<_>>();
+        let len = aggregate_into(&mut blocks);
+        blocks.truncate(len);
+        blocks
+    }
+
+    #[inline]
+    //Returns ranges of addresses covered by the set for each family, in order of `FAMILY_ADDRS`
+    fn intervals(&self) -> [Vec<(u128, u128)>; 2] {
+        [self.v4.intervals(), self.v6.intervals()]
+    }
+
+    //Calls `f` with minimal blocks covering addresses within `self` but not within `other`, in ascending order
+    fn for_each_difference_block(&self, other: &CidrSet, mut f: impl FnMut(Cidr)) {
+        let [other_v4, other_v6] = other.intervals();
+        let [v4, v6] = self.intervals();
+        for (addr, ranges) in FAMILY_ADDRS.into_iter().zip([subtract(&v4, &other_v4), subtract(&v6, &other_v6)]) {
+            for (start, end) in ranges {
+                for_each_range_block(addr, start, end, &mut f);
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over `(network, broadcast)` addresses of each block, after set is collapsed
+    ///
+    ///Overlapping and adjacent blocks are merged first, see [aggregate](fn.aggregate.html),
+    ///hence ranges are disjoint and follow ascending order of the set.
+    pub fn ranges(&self) -> RangeIter {
+        RangeIter {
+            blocks: self.collapsed().into_iter(),
+        }
+    }
+
+    ///Returns number of blocks of the set for each prefix length
+    ///
+    ///Blocks are counted as inserted, without collapsing, and blocks of both families are counted together.
+    pub fn prefix_histogram(&self) -> BTreeMap<u8, usize> {
+        let mut result = BTreeMap::new();
+        for cidr in self.iter() {
+            *result.entry(cidr.prefix()).or_insert(0) += 1;
+        }
+        result
+    }
+
+    ///Returns blocks of the set grouped by `/prefix` supernet containing them
+    ///
+    ///Blocks with prefix shorter than `prefix` have no such supernet and are left out,
+    ///which includes all IPv4 blocks when `prefix` is greater than 32.
+    ///Blocks within each group are in ascending order.
+    pub fn group_by_supernet(&self, prefix: u8) -> BTreeMap<Cidr, Vec<Cidr>> {
+        let mut result = BTreeMap::<Cidr, Vec<Cidr>>::new();
+        for cidr in self.iter() {
+            if cidr.prefix() < prefix {
+                continue;
+            }
+            if let Some(supernet) = Cidr::at_prefix(cidr.addr(), prefix) {
+                result.entry(supernet).or_default().push(cidr);
+            }
+        }
+        result
+    }
+
+    ///Returns number of blocks needed to cover addresses within `self` but not within `other`
+    ///
+    ///Blocks are the same as minimal list covering the difference, but are only counted without being collected.
+    pub fn difference_count(&self, other: &CidrSet) -> usize {
+        let mut count = 0;
+        self.for_each_difference_block(other, |_| count += 1);
+        count
+    }
+
+    ///Returns minimal lists of blocks covering addresses removed from and added to `self` to get `other`
+    ///
+    ///First list covers addresses within `self` but not `other`, while second covers addresses within `other` but not `self`.
+    ///Both are in ascending order with host bits cleared.
+    pub fn diff(&self, other: &CidrSet) -> (Vec<Cidr>, Vec<Cidr>) {
+        let mut removed = Vec::new();
+        self.for_each_difference_block(other, |cidr| removed.push(cidr));
+        let mut added = Vec::new();
+        other.for_each_difference_block(self, |cidr| added.push(cidr));
+        (removed, added)
+    }
+
+    //Returns ranges of addresses of `cidr` that are not within any block of the set
+    fn uncovered(&self, cidr: &Cidr) -> Vec<(u128, u128)> {
+        let covered = match cidr {
+            Cidr::V4(_) => self.v4.intervals(),
+            Cidr::V6(_) => self.v6.intervals(),
+        };
+        subtract(&[(addr_bits(cidr.network_addr()), addr_bits(cidr.broadcast_addr()))], &covered)
+    }
+
+    #[inline]
+    ///Returns whether every address of `cidr` is within blocks of the set
+    ///
+    ///Unlike [contains](#method.contains) block may be covered by several blocks of the set, e.g. `/24` by two `/25`
+    pub fn contains_block(&self, cidr: &Cidr) -> bool {
+        self.uncovered(cidr).is_empty()
+    }
+
+    ///Returns minimal list of blocks covering addresses of `bound` that are not within any block of the set
+    ///
+    ///This is free space of the `bound` pool, with blocks in ascending order and host bits cleared.
+    pub fn gaps_within(&self, bound: &Cidr) -> Vec<Cidr> {
+        let mut result = Vec::new();
+        for (start, end) in self.uncovered(bound) {
+            for_each_range_block(bound.addr(), start, end, |cidr| result.push(cidr));
+        }
+        result
+    }
+
+    ///Encodes blocks of the set into compact binary form, which is inverse of [from_packed_bytes](#method.from_packed_bytes)
+    ///
+    ///Layout is header followed by entries in ascending order:
+    ///- Header is magic `CIDR`, version byte `1` and number of entries as big endian `u64`;
+    ///- Entry is family byte (`4` or `6`), prefix byte and network address octets (4 or 16).
+    pub fn to_packed_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(PACKED_HEADER_LEN + self.len * (2 + 16));
+        result.extend_from_slice(&PACKED_MAGIC);
+        result.push(PACKED_VERSION);
+        result.extend_from_slice(&(self.len as u64).to_be_bytes());
+        for cidr in self.iter() {
+            result.push(match cidr {
+                Cidr::V4(_) => PACKED_V4,
+                Cidr::V6(_) => PACKED_V6,
+            });
+            result.push(cidr.prefix());
+            result.extend_from_slice(&cidr.to_bytes());
+        }
+        result
+    }
+
+    ///Decodes set from bytes produced by [to_packed_bytes](#method.to_packed_bytes)
+    ///
+    ///Returns `Err` if header is invalid, any entry is malformed or number of entries does not match header
+    pub fn from_packed_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < PACKED_HEADER_LEN {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (magic, rest) = bytes.split_at(PACKED_MAGIC.len());
+        if magic != PACKED_MAGIC {
+            return Err(DecodeError::InvalidMagic);
+        }
+        if rest[0] != PACKED_VERSION {
+            return Err(DecodeError::UnsupportedVersion(rest[0]));
+        }
+        let mut count = [0u8; 8];
+        count.copy_from_slice(&rest[1..9]);
+        let count = u64::from_be_bytes(count);
+
+        let mut result = Self::new();
+        let mut offset = PACKED_HEADER_LEN;
+        for _ in 0..count {
+            let addr_len = match bytes.get(offset) {
+                Some(&PACKED_V4) => 4,
+                Some(&PACKED_V6) => 16,
+                Some(_) => return Err(DecodeError::InvalidEntry(offset)),
+                None => return Err(DecodeError::UnexpectedEnd),
+            };
+            let (prefix, addr) = match bytes.get(offset + 1..offset + 2 + addr_len) {
+                Some(entry) => (entry[0], &entry[1..]),
+                None => return Err(DecodeError::UnexpectedEnd),
+            };
+            match Cidr::from_bytes(addr, prefix) {
+                Some(cidr) => result.insert(cidr),
+                None => return Err(DecodeError::InvalidEntry(offset)),
+            };
+            offset += 2 + addr_len;
+        }
+
+        if offset == bytes.len() {
+            Ok(result)
+        } else {
+            Err(DecodeError::TrailingData)
+        }
+    }
+
+    ///Inserts `cidr` into the set
+    ///
+    ///Returns `false` if block with the same network address and prefix is already present
+    pub fn insert(&mut self, cidr: Cidr) -> bool {
+        let is_new = match cidr {
+            Cidr::V4(cidr) => self.v4.insert(cidr.network_addr().to_bits() as _, cidr.prefix()).1,
+            Cidr::V6(cidr) => self.v6.insert(cidr.network_addr().to_bits(), cidr.prefix()).1,
+        };
+        self.len += is_new as usize;
+        is_new
+    }
+
+    ///Returns block of the set with the same network address and prefix as `cidr`
+    ///
+    ///Unlike [longest_match](#method.longest_match), blocks containing `cidr` are not considered
+    pub fn get_exact(&self, cidr: &Cidr) -> Option<Cidr> {
+        let idx = match cidr {
+            Cidr::V4(cidr) => self.v4.find(cidr.network_addr().to_bits() as _, cidr.prefix()),
+            Cidr::V6(cidr) => self.v6.find(cidr.network_addr().to_bits(), cidr.prefix()),
+        };
+        idx.map(|_| cidr.canonical())
+    }
+
+    #[inline]
+    ///Returns whether `addr` is contained within any block of the set
+    pub fn contains(&self, addr: net::IpAddr) -> bool {
+        self.longest_match(addr).is_some()
+    }
+
+    #[inline(always)]
+    ///Returns block of the set containing `addr`
+    ///
+    ///Same as [longest_match](#method.longest_match)
+    pub fn matching(&self, addr: net::IpAddr) -> Option<Cidr> {
+        self.longest_match(addr)
+    }
+
+    ///Returns iterator over all blocks of the set containing `addr`, ordered from the most to the least specific
+    ///
+    ///Blocks are found on the path from the root of the trie to the longest match, which is then walked backwards.
+    pub fn matches(&self, addr: net::IpAddr) -> MatchIter {
+        let mut prefixes = Vec::new();
+        match addr {
+            net::IpAddr::V4(addr) => self.v4.for_each_match(addr.to_bits() as _, |prefix, _| prefixes.push(prefix)),
+            net::IpAddr::V6(addr) => self.v6.for_each_match(addr.to_bits(), |prefix, _| prefixes.push(prefix)),
+        }
+        MatchIter {
+            addr,
+            prefixes,
+        }
+    }
+
+    ///Returns the most specific block of the set containing `addr`, i.e. block with the largest prefix
+    ///
+    ///Blocks of equal prefix containing the same address are identical, hence result is never ambiguous.
+    pub fn longest_match(&self, addr: net::IpAddr) -> Option<Cidr> {
+        let prefix = match addr {
+            net::IpAddr::V4(addr) => self.v4.longest_match(addr.to_bits() as _),
+            net::IpAddr::V6(addr) => self.v6.longest_match(addr.to_bits()),
+        };
+        match prefix {
+            Some(prefix) => Cidr::at_prefix(addr, prefix),
+            None => None,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CidrSet {
+    type Item = Cidr;
+    type IntoIter = CidrSetIter<'a>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PartialEq for CidrSet {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for CidrSet {
+}
+
+impl Default for CidrSet {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<Cidr> for CidrSet {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Cidr>>(&mut self, iter: I) {
+        for cidr in iter {
+            self.insert(cidr);
+        }
+    }
+}
+
+impl FromIterator<Cidr> for CidrSet {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Cidr>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+///Builder accumulating blocks to be normalized into [CidrSet](struct.CidrSet.html) at once
+///
+///Blocks are aggregated and sorted only when set is built, which is cheaper than normalizing on every insert
+///when loading large lists of blocks.
+pub struct CidrSetBuilder {
+    blocks: Vec<Cidr>,
+}
+
+impl CidrSetBuilder {
+    #[inline]
+    ///Creates empty builder
+    pub const fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+        }
+    }
+
+    #[inline]
+    ///Adds `cidr` to the set
+    pub fn add(&mut self, cidr: Cidr) -> &mut Self {
+        self.blocks.push(cidr);
+        self
+    }
+
+    #[inline]
+    ///Adds minimal blocks covering `range` to the set
+    pub fn add_range(&mut self, range: IpRange) -> &mut Self {
+        let (start, end) = range.to_bounds();
+        for_each_range_block(range.start(), start, end, |cidr| self.blocks.push(cidr));
+        self
+    }
+
+    #[inline]
+    ///Parses `text` with [parse_cidr_exact](fn.parse_cidr_exact.html) and adds result to the set
+    ///
+    ///Returns `Err` if `text` is not valid CIDR, leaving builder unchanged
+    pub fn add_str<'a>(&mut self, text: &'a str) -> Result<&mut Self, ParseError<'a>> {
+        let cidr = parse_cidr_exact(text)?;
+        Ok(self.add(cidr))
+    }
+
+    ///Builds set out of the smallest equivalent list of added blocks, see [aggregate](fn.aggregate.html)
+    ///
+    ///Builder is left empty afterwards
+    pub fn build(&mut self) -> CidrSet {
+        let mut blocks = core::mem::take(&mut self.blocks);
+        let len = aggregate_into(&mut blocks);
+        blocks.truncate(len);
+        blocks.into_iter().collect()
+    }
+}
+
+impl Cidr {
+    ///Returns fraction of addresses of `self` covered by blocks of `subset`, within `[0.0, 1.0]`
+    ///
+    ///Blocks of `subset` are collapsed first, so overlapping blocks are not counted twice.
+    ///Instead of dividing address counts, which do not fit `u128` for `/0`, each covered block contributes
+    ///`2^-(block.prefix() - self.prefix())` constructed directly from `f64` bits, hence no `std` math is needed.
+    pub fn coverage_fraction(&self, subset: &CidrSet) -> f64 {
+        let mut fraction = 0.0;
+        for block in subset.collapsed() {
+            if block.contains_cidr(self) {
+                return 1.0;
+            } else if self.contains_cidr(&block) {
+                //Difference is at most 128, which is well within exponent range
+                let depth = (block.prefix() - self.prefix()) as u64;
+                fraction += f64::from_bits((1023 - depth) << 52);
+            }
+        }
+        fraction.min(1.0)
+    }
+
+    ///Returns the largest supernet of `self` which does not overlap any block of `avoid`
+    ///
+    ///Supernets are taken for as long as the next one remains disjoint from `avoid`, hence:
+    ///- If `self` already overlaps `avoid`, result is `None` as every supernet overlaps too;
+    ///- If `avoid` has no blocks of the same family, result is `/0` block.
+    pub fn grow_until_disjoint(&self, avoid: &CidrSet) -> Option<Cidr> {
+        let prefix = match self {
+            Cidr::V4(cidr) => avoid.v4.disjoint_prefix(cidr.network_addr().to_bits() as _, cidr.prefix()),
+            Cidr::V6(cidr) => avoid.v6.disjoint_prefix(cidr.network_addr().to_bits(), cidr.prefix()),
+        };
+        match prefix {
+            Some(prefix) => Cidr::at_prefix(self.addr(), prefix),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use core::iter::Step;
+use core::net;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///Address wrapper implementing [Step](https://doc.rust-lang.org/core/iter/trait.Step.html), enabling ranges of addresses
+///
+///E.g. `StepAddr(start)..=StepAddr(end)` iterates over every address from `start` to `end`
+pub struct StepAddr<A>(pub A);
+
+macro_rules! impl_step {
+    ($typ:ty where REPR=$repr:ident) => {
+        impl Step for StepAddr<$typ> {
+            #[inline]
+            fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+                match end.0.to_bits().checked_sub(start.0.to_bits()) {
+                    Some(steps) => match usize::try_from(steps) {
+                        Ok(steps) => (steps, Some(steps)),
+                        Err(_) => (usize::MAX, None),
+                    },
+                    None => (0, None),
+                }
+            }
+
+            #[inline]
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                let count = $repr::try_from(count).ok()?;
+                start.0.to_bits().checked_add(count).map(|addr| Self(<$typ>::from_bits(addr)))
+            }
+
+            #[inline]
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                let count = $repr::try_from(count).ok()?;
+                start.0.to_bits().checked_sub(count).map(|addr| Self(<$typ>::from_bits(addr)))
+            }
+        }
+    }
+}
+
+impl_step!(net::Ipv4Addr where REPR=u32);
+impl_step!(net::Ipv6Addr where REPR=u128);
@@ -0,0 +1,63 @@
+//![proptest](https://docs.rs/proptest) strategies
+//!
+//!Generated blocks shrink towards smaller prefix and unspecified address, while [Cidr](../enum.Cidr.html) shrinks towards IPv4.
+
+use core::net;
+
+use proptest::prelude::{any, prop_oneof, BoxedStrategy, Strategy};
+
+use crate::{v4, v6, Cidr};
+
+#[inline]
+///Returns strategy generating any IPv4 block
+pub fn any_v4_cidr() -> impl Strategy<Value = v4::Cidr> {
+    (any::<u32>(), 0..=v4::BITS_LEN).prop_map(|(addr, prefix)| match v4::Cidr::new(net::Ipv4Addr::from_bits(addr), prefix) {
+        Some(cidr) => cidr,
+        None => unreachable!(),
+    })
+}
+
+#[inline]
+///Returns strategy generating any IPv6 block
+pub fn any_v6_cidr() -> impl Strategy<Value = v6::Cidr> {
+    (any::<u128>(), 0..=v6::BITS_LEN).prop_map(|(addr, prefix)| match v6::Cidr::new(net::Ipv6Addr::from_bits(addr), prefix) {
+        Some(cidr) => cidr,
+        None => unreachable!(),
+    })
+}
+
+#[inline]
+///Returns strategy generating any block of either family
+pub fn any_cidr() -> impl Strategy<Value = Cidr> {
+    prop_oneof![
+        any_v4_cidr().prop_map(Cidr::V4),
+        any_v6_cidr().prop_map(Cidr::V6),
+    ]
+}
+
+#[inline]
+///Returns strategy generating blocks with `prefix`
+///
+///Prefix greater than 32 generates only IPv6 blocks.
+///
+///## Panics
+///
+///If `prefix` is greater than 128
+pub fn cidr_with_prefix(prefix: u8) -> BoxedStrategy<Cidr> {
+    assert!(prefix <= v6::BITS_LEN, "prefix must not be greater than 128");
+
+    //Only IPv6 is generated when prefix doesn't fit IPv4, so that shrinking never switches family
+    let v6 = any::<u128>().prop_map(move |addr| match Cidr::new_v6(net::Ipv6Addr::from_bits(addr), prefix) {
+        Some(cidr) => cidr,
+        None => unreachable!(),
+    });
+    if prefix > v4::BITS_LEN {
+        return v6.boxed();
+    }
+
+    let v4 = any::<u32>().prop_map(move |addr| match Cidr::new_v4(net::Ipv4Addr::from_bits(addr), prefix) {
+        Some(cidr) => cidr,
+        None => unreachable!(),
+    });
+    prop_oneof![v4, v6].boxed()
+}
@@ -8,9 +8,179 @@ pub(crate) const BITS_LEN: u8 = net::Ipv4Addr::BITS as u8;
 
 ///IPv4 CIDR
 pub type Cidr = base::Cidr<net::Ipv4Addr>;
+///Iterator over IPv4 addresses of the block
+pub type CidrIter = base::CidrIter<net::Ipv4Addr>;
+///Iterator over IPv4 subnets of the block
+pub type SubnetIter = base::SubnetIter<net::Ipv4Addr>;
 
 impl base::NetworkAddress for net::Ipv4Addr {
     const BITS_LEN: u8 = BITS_LEN;
+
+    #[inline(always)]
+    fn mask(prefix: u8) -> Self {
+        mask(prefix)
+    }
+
+    #[inline(always)]
+    fn network(self, prefix: u8) -> Self {
+        Self::from_bits(self.to_bits() & mask(prefix).to_bits())
+    }
 }
 
 crate::base::impl_base_methods!(net::Ipv4Addr where REPR=u32);
+
+#[inline]
+///Returns prefix length of the contiguous `mask`, or `None` if mask is not contiguous
+pub const fn prefix_from_netmask(mask: net::Ipv4Addr) -> Option<u8> {
+    let bits = mask.to_bits();
+    let prefix = bits.leading_ones();
+    if prefix + bits.trailing_zeros() == u32::BITS {
+        Some(prefix as u8)
+    } else {
+        None
+    }
+}
+
+impl Cidr {
+    #[inline]
+    ///Returns conventional gateway of the block, which is its first usable host
+    ///
+    ///- For prefix up to 30 it is the address following network address
+    ///- For /31 it is network address as both addresses are usable (RFC 3021)
+    ///- For /32 there is no gateway, hence `None`
+    pub const fn default_gateway(&self) -> Option<net::Ipv4Addr> {
+        match self.prefix() {
+            32 => None,
+            31 => Some(self.network_addr()),
+            _ => Some(self.get_unchecked(1)),
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over usable host addresses of the block
+    ///
+    ///- For prefix up to 30 network and broadcast addresses are excluded
+    ///- For /31 and /32 all addresses are usable (RFC 3021)
+    pub const fn hosts(&self) -> CidrIter {
+        let mut iter = self.iter();
+        if self.prefix() <= 30 {
+            iter.front = net::Ipv4Addr::from_bits(iter.front.to_bits() + 1);
+            iter.back = net::Ipv4Addr::from_bits(iter.back.to_bits() - 1);
+        }
+        iter
+    }
+
+    #[inline]
+    ///Returns number of usable host addresses of the block
+    ///
+    ///- For prefix up to 30 network and broadcast addresses are excluded
+    ///- For /31 and /32 all addresses are usable (RFC 3021)
+    pub const fn host_count(&self) -> u32 {
+        match self.prefix() {
+            32 | 31 => self.size(),
+            //Size saturates for /0, while wildcard is always one less than number of addresses
+            _ => self.wildcard_mask().to_bits() - 1,
+        }
+    }
+
+    #[inline]
+    ///Returns block within IPv4-mapped IPv6 range `::ffff:0:0/96` corresponding to `self`, with prefix `96 + prefix`
+    pub const fn to_ipv6_mapped(&self) -> crate::v6::Cidr {
+        match crate::v6::Cidr::new(self.addr().to_ipv6_mapped(), crate::v6::MAPPED_IPV4_PREFIX + self.prefix()) {
+            Some(cidr) => cidr,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    ///Returns whether `addr` is [default gateway](#method.default_gateway) of the block
+    pub const fn is_default_gateway(&self, addr: net::Ipv4Addr) -> bool {
+        match self.default_gateway() {
+            Some(gateway) => gateway.to_bits() == addr.to_bits(),
+            None => false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Minimum prefix of the block that can be represented by [BitmapVec](struct.BitmapVec.html)
+///
+///Block of this size has 65536 addresses, which takes 8KiB of memory
+pub const BITMAP_MIN_PREFIX: u8 = 16;
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Bitset of the block's addresses, indexed relative to the network address
+pub struct BitmapVec {
+    len: u32,
+    words: alloc::vec::Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl BitmapVec {
+    #[inline(always)]
+    ///Returns number of bits
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether bitmap has no bits
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    ///Returns whether bit at `idx` is set, or `false` if `idx` is out of bounds
+    pub fn get(&self, idx: u32) -> bool {
+        idx < self.len && self.words[(idx / u64::BITS) as usize] & (1 << (idx % u64::BITS)) != 0
+    }
+
+    #[inline]
+    ///Sets bit at `idx` to `value`
+    ///
+    ///Returns `false` if `idx` is out of bounds
+    pub fn set(&mut self, idx: u32, value: bool) -> bool {
+        if idx >= self.len {
+            return false;
+        }
+
+        let word = &mut self.words[(idx / u64::BITS) as usize];
+        if value {
+            *word |= 1 << (idx % u64::BITS);
+        } else {
+            *word &= !(1 << (idx % u64::BITS));
+        }
+        true
+    }
+
+    #[inline(always)]
+    ///Returns underlying words, with bit `idx` stored at `idx % 64` of the word `idx / 64`
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Cidr {
+    ///Creates bitmap with a bit set for every address within the block
+    ///
+    ///Returns `None` if prefix is less than [BITMAP_MIN_PREFIX](constant.BITMAP_MIN_PREFIX.html)
+    pub fn to_bitmap(&self) -> Option<BitmapVec> {
+        if self.prefix() < BITMAP_MIN_PREFIX {
+            return None;
+        }
+
+        let len = self.size();
+        let mut words = alloc::vec![u64::MAX; len.div_ceil(u64::BITS) as usize];
+        //Clear bits past the end of the block
+        if let Some(last) = words.last_mut() {
+            *last >>= (u64::BITS - len % u64::BITS) % u64::BITS;
+        }
+
+        Some(BitmapVec {
+            len,
+            words,
+        })
+    }
+}
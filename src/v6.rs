@@ -8,9 +8,75 @@ pub(crate) const BITS_LEN: u8 = net::Ipv6Addr::BITS as u8;
 
 ///IPv6 CIDR
 pub type Cidr = base::Cidr<net::Ipv6Addr>;
+///Iterator over IPv6 addresses of the block
+///
+///Blocks shorter than `/64` may have more addresses than `usize` can hold, in which case `len()` is `usize::MAX`
+///and `size_hint()` has no upper bound.
+pub type CidrIter = base::CidrIter<net::Ipv6Addr>;
+///Iterator over IPv6 subnets of the block
+///
+///Splitting into prefix more than 63 bits longer may produce more subnets than `usize` can hold,
+///in which case `len()` is `usize::MAX` and `size_hint()` has no upper bound.
+pub type SubnetIter = base::SubnetIter<net::Ipv6Addr>;
+
+//Prefix of IPv4-mapped range `::ffff:0:0/96`
+pub(crate) const MAPPED_IPV4_PREFIX: u8 = 96;
 
 impl base::NetworkAddress for net::Ipv6Addr {
     const BITS_LEN: u8 = BITS_LEN;
+
+    #[inline(always)]
+    fn mask(prefix: u8) -> Self {
+        mask(prefix)
+    }
+
+    #[inline(always)]
+    fn network(self, prefix: u8) -> Self {
+        Self::from_bits(self.to_bits() & mask(prefix).to_bits())
+    }
 }
 
 crate::base::impl_base_methods!(net::Ipv6Addr where REPR=u128);
+
+#[inline]
+///Returns prefix length of the contiguous `mask`, or `None` if mask is not contiguous
+pub const fn prefix_from_netmask(mask: net::Ipv6Addr) -> Option<u8> {
+    let bits = mask.to_bits();
+    let prefix = bits.leading_ones();
+    if prefix + bits.trailing_zeros() == u128::BITS {
+        Some(prefix as u8)
+    } else {
+        None
+    }
+}
+
+impl Cidr {
+    #[inline(always)]
+    ///Returns iterator over usable host addresses of the block
+    ///
+    ///IPv6 has no broadcast address, hence all addresses are considered usable, same as [iter](#method.iter)
+    pub const fn hosts(&self) -> CidrIter {
+        self.iter()
+    }
+    #[inline(always)]
+    ///Returns number of usable host addresses of the block
+    ///
+    ///IPv6 has no broadcast address and subnet-router anycast address is assignable to routers, hence it is the same as [size](#method.size)
+    pub const fn host_count(&self) -> u128 {
+        self.size()
+    }
+    #[inline]
+    ///Returns IPv4 block corresponding to `self` within IPv4-mapped range `::ffff:0:0/96`, with prefix `prefix - 96`
+    ///
+    ///Returns `None` if prefix is less than 96 or block is outside of IPv4-mapped range
+    pub const fn to_ipv4(&self) -> Option<crate::v4::Cidr> {
+        if self.prefix() < MAPPED_IPV4_PREFIX {
+            return None;
+        }
+
+        match self.addr().to_ipv4_mapped() {
+            Some(addr) => crate::v4::Cidr::new(addr, self.prefix() - MAPPED_IPV4_PREFIX),
+            None => None,
+        }
+    }
+}
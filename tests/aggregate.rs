@@ -0,0 +1,70 @@
+use ip_cidr::{aggregate_into, cidr, Cidr};
+
+#[test]
+fn should_aggregate_in_place() {
+    let mut blocks = [
+        cidr!("8000::/1"),
+        cidr!("10.0.0.128/25"),
+        cidr!("192.168.0.0/24"),
+        cidr!("10.0.0.5/32"),
+        cidr!("10.0.1.0/24"),
+        cidr!("::/1"),
+        cidr!("10.0.0.0/25"),
+        cidr!("192.168.0.0/24"),
+    ];
+    let len = aggregate_into(&mut blocks);
+    assert_eq!(blocks[..len], [
+        cidr!("10.0.0.0/23"),
+        cidr!("192.168.0.0/24"),
+        cidr!("::/0"),
+    ]);
+}
+
+#[test]
+fn should_aggregate_sibling_chains() {
+    //Merging a pair creates sibling for already compacted block
+    let mut blocks = [
+        cidr!("10.0.0.0/24"),
+        cidr!("10.0.1.0/25"),
+        cidr!("10.0.1.128/26"),
+        cidr!("10.0.1.192/26"),
+        cidr!("10.0.2.0/24"),
+    ];
+    let len = aggregate_into(&mut blocks);
+    assert_eq!(blocks[..len], [
+        cidr!("10.0.0.0/23"),
+        cidr!("10.0.2.0/24"),
+    ]);
+
+    //Host bits are cleared, but non-siblings stay separate
+    let mut blocks = [
+        cidr!("10.0.1.7/24"),
+        cidr!("10.0.2.1/24"),
+        cidr!("0.0.0.0/32"),
+        cidr!("255.255.255.255/32"),
+    ];
+    let len = aggregate_into(&mut blocks);
+    assert_eq!(blocks[..len], [
+        cidr!("0.0.0.0/32"),
+        cidr!("10.0.1.0/24"),
+        cidr!("10.0.2.0/24"),
+        cidr!("255.255.255.255/32"),
+    ]);
+
+    let mut blocks: [Cidr; 0] = [];
+    assert_eq!(aggregate_into(&mut blocks), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_aggregate_into_new_list() {
+    let blocks = [
+        cidr!("2001:db8::1/128"),
+        cidr!("10.0.0.128/25"),
+        cidr!("10.0.0.0/25"),
+        cidr!("10.0.0.64/26"),
+        cidr!("2001:db8::/32"),
+    ];
+    assert_eq!(ip_cidr::aggregate(&blocks), [cidr!("10.0.0.0/24"), cidr!("2001:db8::/32")]);
+    assert_eq!(ip_cidr::aggregate(&[]), []);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use ip_cidr::{parse_cidr_exact, v4, v6, Cidr};
+
+//Deterministic pseudo random input
+fn input(seed: u64) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+    (0..512).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u8
+    }).collect()
+}
+
+#[test]
+fn should_generate_valid_blocks() {
+    for seed in 0..64 {
+        let input = input(seed);
+        let mut u = Unstructured::new(&input);
+        while !u.is_empty() {
+            let cidr = v4::Cidr::arbitrary(&mut u).expect("to generate");
+            assert!(cidr.prefix() <= 32);
+            let cidr = v6::Cidr::arbitrary(&mut u).expect("to generate");
+            assert!(cidr.prefix() <= 128);
+        }
+    }
+}
+
+#[test]
+fn should_roundtrip_generated_blocks_through_parser() {
+    for seed in 0..64 {
+        let input = input(seed);
+        let mut u = Unstructured::new(&input);
+        while !u.is_empty() {
+            let cidr = Cidr::arbitrary(&mut u).expect("to generate");
+            let text = cidr.to_string();
+            assert_eq!(parse_cidr_exact(&text), Ok(cidr), "{text}");
+        }
+    }
+}
+
+#[test]
+fn should_generate_from_empty_input() {
+    let mut u = Unstructured::new(&[]);
+    let cidr = Cidr::arbitrary(&mut u).expect("to generate");
+    assert_eq!(cidr, Cidr::new_v4(core::net::Ipv4Addr::UNSPECIFIED, 0).unwrap());
+}
@@ -0,0 +1,31 @@
+use core::net;
+
+use ip_cidr::Cidr;
+
+#[test]
+fn should_construct_from_bytes() {
+    let cidr = Cidr::from_bytes(&[10, 0, 0, 5], 8).expect("to create");
+    assert_eq!(cidr, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 5), 8).unwrap());
+    assert_eq!(cidr.to_bytes().as_slice(), [10, 0, 0, 5]);
+    assert_eq!(Cidr::from_bytes(&cidr.to_bytes(), cidr.prefix()), Some(cidr));
+
+    let addr = net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    let cidr = Cidr::from_bytes(&addr.octets(), 64).expect("to create");
+    assert_eq!(cidr, Cidr::new_v6(addr, 64).unwrap());
+    assert_eq!(*cidr.to_bytes(), addr.octets());
+    assert_eq!(cidr.to_bytes().len(), 16);
+    assert_eq!(Cidr::from_bytes(cidr.to_bytes().as_ref(), cidr.prefix()), Some(cidr));
+
+    const CIDR: Option<Cidr> = Cidr::from_bytes(&[192, 168, 0, 0], 16);
+    assert!(CIDR.is_some());
+}
+
+#[test]
+fn should_not_construct_from_invalid_bytes() {
+    assert_eq!(Cidr::from_bytes(&[10, 0, 0, 5], 33), None);
+    assert_eq!(Cidr::from_bytes(&[0; 16], 129), None);
+    assert_eq!(Cidr::from_bytes(&[], 0), None);
+    assert_eq!(Cidr::from_bytes(&[10, 0, 0], 8), None);
+    assert_eq!(Cidr::from_bytes(&[0; 5], 8), None);
+    assert_eq!(Cidr::from_bytes(&[0; 17], 8), None);
+}
@@ -0,0 +1,98 @@
+use ip_cidr::{parse_cidr, parse_ip};
+
+fn assert_no_panic(text: &str) {
+    let ip = parse_ip(text);
+    let cidr = parse_cidr(text);
+    //Both functions share the same parser
+    assert_eq!(ip.is_ok(), cidr.is_ok(), "'{}' parse_ip={:?} parse_cidr={:?}", text.escape_debug(), ip, cidr);
+}
+
+#[test]
+fn should_not_panic_on_tricky_input() {
+    let inputs = [
+        ("", false),
+        ("/", false),
+        ("//", false),
+        (".", false),
+        (":", false),
+        (":::", false),
+        ("::/", false),
+        ("::/0", true),
+        ("::/+0", false),
+        ("::/-0", false),
+        ("1.2.3.4/+8", false),
+        ("1.2.3.4/8/8", false),
+        ("1.2.3.4/256", false),
+        ("1.2.3.4/99999999999999999999", false),
+        ("1.2.3.4\0", false),
+        ("\0", false),
+        ("1.2.\x003.4", false),
+        ("::\0", false),
+        ("1.2.3.4/\0", false),
+        ("1.2.3.4/é", false),
+        ("é", false),
+        ("1.2.3.é", false),
+        ("::é", false),
+        ("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", false),
+        ("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", true),
+        ("fffff::", false),
+        ("000001.0.0.0", false),
+        ("1.2.3.4.5.6.7.8.9", false),
+        ("1:2:3:4:5:6:7:8:9:10:11:12:13:14:15:16:17", false),
+        ("::1.2.3.4.5.6.7.8.9", false),
+        ("1:2:3:4:5:6:7:8:1.2.3.4", false),
+        ("::ffff:1.2.3.4", true),
+        ("::ffff:1.2.3.4/129", false),
+        ("\u{feff}1.2.3.4", false),
+        ("1.2.3.4\u{200b}", false),
+        ("１.２.３.４", false),
+    ];
+
+    for (text, is_valid) in inputs {
+        println!("Parse '{}'", text.escape_debug());
+        assert_no_panic(text);
+        assert_eq!(parse_ip(text).is_ok(), is_valid, "'{}' has unexpected result", text.escape_debug());
+    }
+}
+
+#[test]
+fn should_not_panic_on_long_digit_runs() {
+    for digit in ["0", "1", "9", "f"] {
+        for len in [1, 2, 3, 4, 5, 16, 40, 1024] {
+            let run = digit.repeat(len);
+            let inputs = [
+                run.clone(),
+                format!("{run}.0.0.0"),
+                format!("0.0.0.{run}"),
+                format!("{run}::"),
+                format!("::{run}"),
+                format!("::ffff:{run}.0.0.0"),
+                format!("0.0.0.0/{run}"),
+                format!("::/{run}"),
+            ];
+
+            for text in inputs.iter() {
+                assert_no_panic(text);
+            }
+        }
+    }
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn should_not_panic_on_all_short_inputs() {
+    const ALPHABET: &[char] = &['0', '1', 'f', '.', ':', '/', '%', ' ', 'é', '\0'];
+    const MAX_LEN: u32 = 6;
+
+    let mut text = String::new();
+    for len in 0..=MAX_LEN {
+        for mut seed in 0..ALPHABET.len().pow(len) {
+            text.clear();
+            for _ in 0..len {
+                text.push(ALPHABET[seed % ALPHABET.len()]);
+                seed /= ALPHABET.len();
+            }
+            assert_no_panic(&text);
+        }
+    }
+}
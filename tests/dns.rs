@@ -0,0 +1,27 @@
+#![cfg(feature = "alloc")]
+
+use ip_cidr::cidr;
+
+#[test]
+fn should_produce_aligned_reverse_zone() {
+    assert_eq!(cidr!("192.168.0.0/24").reverse_dns_zones(), ["0.168.192.in-addr.arpa"]);
+    assert_eq!(cidr!("10.1.2.3/8").reverse_dns_zones(), ["10.in-addr.arpa"]);
+    assert_eq!(cidr!("10.1.2.3/32").reverse_dns_zones(), ["3.2.1.10.in-addr.arpa"]);
+    assert_eq!(cidr!("0.0.0.0/0").reverse_dns_zones(), ["in-addr.arpa"]);
+
+    assert_eq!(cidr!("2001:db8::/32").reverse_dns_zones(), ["8.b.d.0.1.0.0.2.ip6.arpa"]);
+    assert_eq!(cidr!("2001:db8:abcd::/48").reverse_dns_zones(), ["d.c.b.a.8.b.d.0.1.0.0.2.ip6.arpa"]);
+    assert_eq!(cidr!("::/0").reverse_dns_zones(), ["ip6.arpa"]);
+}
+
+#[test]
+fn should_split_unaligned_reverse_zone() {
+    let zones = cidr!("172.16.0.0/20").reverse_dns_zones();
+    assert_eq!(zones.len(), 16);
+    assert_eq!(zones[0], "0.16.172.in-addr.arpa");
+    assert_eq!(zones[15], "15.16.172.in-addr.arpa");
+
+    assert_eq!(cidr!("10.0.0.6/31").reverse_dns_zones(), ["6.0.0.10.in-addr.arpa", "7.0.0.10.in-addr.arpa"]);
+
+    assert_eq!(cidr!("2001:db8::/31").reverse_dns_zones(), ["8.b.d.0.1.0.0.2.ip6.arpa", "9.b.d.0.1.0.0.2.ip6.arpa"]);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "alloc")]
+
+use core::net;
+
+use ip_cidr::{AddrFamily, Cidr, OwnedParseError, ParseError};
+
+#[test]
+fn should_parse_cidr_from_str() {
+    let cidr = "10.0.0.0/8".parse::<Cidr>().expect("to parse");
+    assert_eq!(cidr, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap());
+    let cidr = "2001:db8::/32".parse::<Cidr>().expect("to parse");
+    assert_eq!(cidr, Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap());
+
+    let cidr = "10.0.0.1".parse::<ip_cidr::v4::Cidr>().expect("to parse");
+    assert_eq!(cidr, ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::new(10, 0, 0, 1)));
+    let cidr = "::1/64".parse::<ip_cidr::v6::Cidr>().expect("to parse");
+    assert_eq!(cidr, ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 64).unwrap());
+}
+
+#[test]
+fn should_return_owned_error_from_str() {
+    let error = "10.0.0.0/8x".parse::<Cidr>().expect_err("should fail");
+    assert_eq!(error, OwnedParseError::InvalidCidr("8x".into()));
+    assert_eq!(error.as_error(), ParseError::InvalidCidr("8x"));
+    assert_eq!(error.to_string(), ip_cidr::parse_cidr("10.0.0.0/8x").unwrap_err().to_string());
+
+    let error = "10.0.0.0/33".parse::<Cidr>().expect_err("should fail");
+    assert_eq!(error, OwnedParseError::Ipv4CidrPrefixOverflow(33));
+
+    let error = "10.0.0.0/8".parse::<ip_cidr::v6::Cidr>().expect_err("should fail");
+    assert_eq!(error, OwnedParseError::FamilyMismatch(AddrFamily::V6));
+    let error = "::/0".parse::<ip_cidr::v4::Cidr>().expect_err("should fail");
+    assert_eq!(error, OwnedParseError::FamilyMismatch(AddrFamily::V4));
+
+    //Error outlives input
+    let error = {
+        let text = String::from("10.0.x.0");
+        text.parse::<Cidr>().expect_err("should fail")
+    };
+    assert_eq!(error, OwnedParseError::UnexpectedCharacter('x', 5));
+}
+
+#[test]
+fn should_parse_cidr_list() {
+    use ip_cidr::parse_cidr_list;
+
+    let list = parse_cidr_list("10.0.0.0/8, 192.168.0.0/16").expect("to parse");
+    assert_eq!(list, [
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(192, 168, 0, 0), 16).unwrap(),
+    ]);
+
+    let list = parse_cidr_list(" ,10.0.0.1\t2001:db8::/32,,\n").expect("to parse");
+    assert_eq!(list, [
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 32).unwrap(),
+        Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap(),
+    ]);
+    assert_eq!(parse_cidr_list("").expect("to parse"), []);
+    assert_eq!(parse_cidr_list(" , ").expect("to parse"), []);
+}
+
+#[test]
+fn should_not_parse_invalid_cidr_list() {
+    use ip_cidr::parse_cidr_list;
+
+    assert_eq!(parse_cidr_list("10.0.0.0/8, 10.0.x.0").unwrap_err(), ParseError::UnexpectedCharacter('x', 17));
+    assert_eq!(parse_cidr_list("10.0.0.0/33 10.0.x.0").unwrap_err(), ParseError::Ipv4CidrPrefixOverflow(33));
+    assert_eq!(parse_cidr_list("10.0.0.0 /8").unwrap_err(), ParseError::MissingIp);
+}
+
+#[test]
+fn should_convert_parse_error_into_owned() {
+    fn parse_owned(text: String) -> Result<Cidr, OwnedParseError> {
+        ip_cidr::parse_cidr_exact(&text).map_err(ParseError::into_owned)
+    }
+
+    for text in ["10.0.0.0/8x", "10.0.x.0", "10.0.0/8", "::1/129", "10.0.0.0/", "10.0.0.0/255.0.255.0"] {
+        let error = ip_cidr::parse_cidr_exact(text).unwrap_err();
+        let owned = parse_owned(text.to_owned()).unwrap_err();
+        assert_eq!(owned.as_error(), error);
+        assert_eq!(owned.to_string(), error.to_string());
+    }
+    assert_eq!(parse_owned("10.0.0.0/8x".to_owned()).unwrap_err(), OwnedParseError::InvalidCidr("8x".into()));
+}
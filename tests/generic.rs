@@ -0,0 +1,143 @@
+use core::{fmt, net};
+
+use ip_cidr::base::{AddressBits, Cidr, CidrOps, NetworkAddress};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Addr8(u8);
+
+impl fmt::Display for Addr8 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl NetworkAddress for Addr8 {
+    const BITS_LEN: u8 = 8;
+
+    fn mask(prefix: u8) -> Self {
+        Self(u8::MAX.checked_shl((Self::BITS_LEN - prefix) as u32).unwrap_or(0))
+    }
+}
+
+impl AddressBits for Addr8 {
+    fn to_bits(self) -> u128 {
+        self.0 as _
+    }
+
+    fn from_bits(bits: u128) -> Self {
+        Self(bits as _)
+    }
+}
+
+#[test]
+fn should_compute_custom_address_block() {
+    let cidr = Cidr::new(Addr8(0b1010_1101), 5).expect("to create");
+    assert_eq!(cidr.network_addr(), Addr8(0b1010_1000));
+    assert_eq!(cidr.broadcast_addr(), Addr8(0b1010_1111));
+    assert_eq!(cidr.size(), 8);
+    assert!(cidr.contains(Addr8(0b1010_1000)));
+    assert!(cidr.contains(Addr8(0b1010_1111)));
+    assert!(!cidr.contains(Addr8(0b1011_0000)));
+    assert_eq!(cidr.get(0), Some(Addr8(0b1010_1000)));
+    assert_eq!(cidr.get(7), Some(Addr8(0b1010_1111)));
+    assert_eq!(cidr.get(8), None);
+
+    let cidr = Cidr::new(Addr8(77), 0).expect("to create");
+    assert_eq!(cidr.network_addr(), Addr8(0));
+    assert_eq!(cidr.broadcast_addr(), Addr8(255));
+    assert_eq!(cidr.size(), 256);
+    assert_eq!(cidr.get(255), Some(Addr8(255)));
+
+    let cidr = Cidr::new_single(Addr8(77));
+    assert_eq!(cidr.size(), 1);
+    assert!(cidr.contains(Addr8(77)));
+    assert!(!cidr.contains(Addr8(78)));
+    assert!(Cidr::new(Addr8(0), 9).is_none());
+}
+
+fn generic_bounds<C: CidrOps>(cidr: &C) -> (C::Addr, C::Addr, u128) {
+    (cidr.network_addr(), cidr.broadcast_addr(), cidr.size())
+}
+
+#[test]
+fn should_match_inherent_methods() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 77), 20).unwrap();
+    assert_eq!(generic_bounds(&cidr), (cidr.network_addr(), cidr.broadcast_addr(), cidr.size() as u128));
+    assert_eq!(CidrOps::get(&cidr, 5), cidr.get(5));
+    assert_eq!(CidrOps::get(&cidr, 4096), None);
+
+    //IPv4 `/0` doesn't fit inherent `u32` size, while trait is exact
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 77), 0).unwrap();
+    assert_eq!(generic_bounds(&cidr), (cidr.network_addr(), cidr.broadcast_addr(), 1 << 32));
+    assert_eq!(cidr.size(), u32::MAX);
+    assert_eq!(cidr.iter().len() as u128, CidrOps::size(&cidr));
+    assert_eq!(CidrOps::get(&cidr, u32::MAX as u128), Some(net::Ipv4Addr::BROADCAST));
+    assert_eq!(cidr.get(u32::MAX), None);
+    assert_eq!(CidrOps::get(&cidr, 1 << 32), None);
+    assert_eq!(CidrOps::get(&cidr, 5), cidr.get(5));
+
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 0).unwrap();
+    assert_eq!(generic_bounds(&cidr), (cidr.network_addr(), cidr.broadcast_addr(), u128::MAX));
+    assert!(CidrOps::contains(&cidr, net::Ipv6Addr::LOCALHOST));
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 32).unwrap();
+    assert_eq!(generic_bounds(&cidr), (cidr.network_addr(), cidr.broadcast_addr(), cidr.size()));
+    assert!(!CidrOps::contains(&cidr, net::Ipv6Addr::LOCALHOST));
+}
+
+fn contains_own_network<A: AddressBits>(cidr: &Cidr<A>) -> bool {
+    cidr.contains(cidr.network_addr()) && cidr.contains(cidr.broadcast_addr())
+}
+
+#[test]
+fn should_compute_mask_generically() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 77), 20).unwrap();
+    assert_eq!(cidr.mask(), cidr.netmask());
+    assert_eq!(cidr.mask(), net::Ipv4Addr::new(255, 255, 240, 0));
+    assert!(contains_own_network(&cidr));
+
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 32).unwrap();
+    assert_eq!(cidr.mask(), cidr.netmask());
+    assert_eq!(cidr.mask(), net::Ipv6Addr::new(0xffff, 0xffff, 0, 0, 0, 0, 0, 0));
+    assert!(contains_own_network(&cidr));
+
+    for prefix in 0..=8 {
+        let cidr = Cidr::new(Addr8(0b1010_1101), prefix).unwrap();
+        assert_eq!(cidr.mask().0.leading_ones(), prefix as u32);
+        assert_eq!(cidr.mask().0.count_ones(), prefix as u32);
+        assert!(contains_own_network(&cidr));
+    }
+}
+
+#[test]
+fn should_order_custom_address_blocks() {
+    let blocks = [
+        Cidr::new(Addr8(0b1010_1101), 5).unwrap(),
+        Cidr::new(Addr8(0b1010_1000), 6).unwrap(),
+        Cidr::new(Addr8(0), 0).unwrap(),
+        Cidr::new(Addr8(0b1010_1000), 5).unwrap(),
+        Cidr::new_single(Addr8(1)),
+    ];
+
+    let mut sorted = blocks;
+    sorted.sort();
+    //Prefix is compared before address
+    assert_eq!(sorted, [
+        Cidr::new(Addr8(0), 0).unwrap(),
+        Cidr::new(Addr8(0b1010_1000), 5).unwrap(),
+        Cidr::new(Addr8(0b1010_1101), 5).unwrap(),
+        Cidr::new(Addr8(0b1010_1000), 6).unwrap(),
+        Cidr::new_single(Addr8(1)),
+    ]);
+
+    let map = blocks.iter().map(|block| (*block, block.size())).collect::<std::collections::BTreeMap<_, _>>();
+    assert_eq!(map.len(), blocks.len());
+    assert_eq!(map[&Cidr::new_single(Addr8(1))], 1);
+}
+
+#[test]
+fn should_display_custom_address_block() {
+    let cidr = Cidr::new(Addr8(0b1010_1101), 5).expect("to create");
+    assert_eq!(cidr.to_string(), "173/5");
+    assert_eq!(format!("{cidr:#}"), "173/5");
+    assert_eq!(Cidr::new_single(Addr8(77)).to_string(), "77/8");
+}
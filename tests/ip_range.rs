@@ -0,0 +1,108 @@
+use core::net;
+
+use ip_cidr::{aligned_boundaries, cidr, AddrFamily, IpRange, RangeError};
+
+#[test]
+fn should_validate_range() {
+    let range = IpRange::new(net::IpAddr::from([10, 0, 0, 5]), net::IpAddr::from([10, 0, 0, 10])).expect("valid range");
+    assert_eq!(range.start(), net::IpAddr::from([10, 0, 0, 5]));
+    assert_eq!(range.end(), net::IpAddr::from([10, 0, 0, 10]));
+    assert!(range.contains(net::IpAddr::from([10, 0, 0, 5])));
+    assert!(range.contains(net::IpAddr::from([10, 0, 0, 10])));
+    assert!(!range.contains(net::IpAddr::from([10, 0, 0, 11])));
+    assert!(!range.contains(net::IpAddr::V6(net::Ipv6Addr::from_bits(5))));
+
+    assert!(IpRange::new(net::IpAddr::from([10, 0, 0, 5]), net::IpAddr::from([10, 0, 0, 5])).is_ok());
+    assert_eq!(IpRange::new(net::IpAddr::from([10, 0, 0, 5]), net::IpAddr::from([10, 0, 0, 4])), Err(RangeError::Empty));
+    assert_eq!(IpRange::new(net::IpAddr::from([0, 0, 0, 0]), net::IpAddr::V6(net::Ipv6Addr::from_bits(1))), Err(RangeError::FamilyMismatch));
+
+    let range = IpRange::from(cidr!("192.168.1.77/24"));
+    assert_eq!((range.start(), range.end()), (net::IpAddr::from([192, 168, 1, 0]), net::IpAddr::from([192, 168, 1, 255])));
+}
+
+#[test]
+fn should_iterate_aligned_boundaries() {
+    let range = IpRange::new(net::IpAddr::from([10, 0, 0, 100]), net::IpAddr::from([10, 0, 3, 0])).expect("valid range");
+    let boundaries = aligned_boundaries(&range, 24).expect("valid prefix").collect::<Vec<_>>();
+    assert_eq!(boundaries, [net::IpAddr::from([10, 0, 1, 0]), net::IpAddr::from([10, 0, 2, 0]), net::IpAddr::from([10, 0, 3, 0])]);
+
+    let range = IpRange::new(net::IpAddr::from([10, 0, 0, 1]), net::IpAddr::from([10, 0, 0, 254])).expect("valid range");
+    assert_eq!(aligned_boundaries(&range, 24).expect("valid prefix").next(), None);
+    assert_eq!(aligned_boundaries(&range, 32).expect("valid prefix").count(), 254);
+    assert!(aligned_boundaries(&range, 33).is_none());
+
+    let range = IpRange::new(net::IpAddr::from([255, 255, 255, 0]), net::IpAddr::from([255, 255, 255, 255])).expect("valid range");
+    assert_eq!(aligned_boundaries(&range, 25).expect("valid prefix").collect::<Vec<_>>(), [net::IpAddr::from([255, 255, 255, 0]), net::IpAddr::from([255, 255, 255, 128])]);
+    let range = IpRange::new(net::IpAddr::from([255, 255, 255, 1]), net::IpAddr::from([255, 255, 255, 255])).expect("valid range");
+    assert_eq!(aligned_boundaries(&range, 0).expect("valid prefix").next(), None);
+    assert_eq!(aligned_boundaries(&IpRange::from(cidr!("0.0.0.0/0")), 0).expect("valid prefix").collect::<Vec<_>>(), [net::IpAddr::from([0, 0, 0, 0])]);
+
+    let range = IpRange::new(net::IpAddr::V6(net::Ipv6Addr::from_bits(1)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))).expect("valid range");
+    assert_eq!(aligned_boundaries(&range, 1).expect("valid prefix").collect::<Vec<_>>(), [net::IpAddr::V6(net::Ipv6Addr::from_bits(1 << 127))]);
+    let range = IpRange::new(net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX - 1)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))).expect("valid range");
+    assert_eq!(aligned_boundaries(&range, 128).expect("valid prefix").collect::<Vec<_>>(), [net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX - 1)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))]);
+}
+
+#[test]
+fn should_intersect_and_merge_ranges() {
+    let range = |start, end| IpRange::new(net::IpAddr::from([10, 0, 0, start]), net::IpAddr::from([10, 0, 0, end])).expect("valid range");
+
+    //Overlapping
+    assert_eq!(range(1, 7).intersection(&range(5, 10)), Some(range(5, 7)));
+    assert_eq!(range(5, 10).intersection(&range(1, 7)), Some(range(5, 7)));
+    assert_eq!(range(1, 7).union(&range(5, 10)), Some(range(1, 10)));
+    assert_eq!(range(5, 10).union(&range(1, 7)), Some(range(1, 10)));
+    assert_eq!(range(1, 10).intersection(&range(3, 4)), Some(range(3, 4)));
+    assert_eq!(range(3, 4).union(&range(1, 10)), Some(range(1, 10)));
+
+    //Adjacent
+    assert_eq!(range(1, 5).intersection(&range(6, 10)), None);
+    assert_eq!(range(1, 5).union(&range(6, 10)), Some(range(1, 10)));
+    assert_eq!(range(6, 10).union(&range(1, 5)), Some(range(1, 10)));
+
+    //Disjoint
+    assert_eq!(range(1, 5).intersection(&range(7, 10)), None);
+    assert_eq!(range(1, 5).union(&range(7, 10)), None);
+    assert_eq!(range(7, 10).union(&range(1, 5)), None);
+
+    let v6_range = IpRange::new(net::IpAddr::V6(net::Ipv6Addr::from_bits(1)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))).expect("valid range");
+    assert_eq!(range(1, 5).intersection(&v6_range), None);
+    assert_eq!(range(1, 5).union(&v6_range), None);
+    let high = IpRange::new(net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))).expect("valid range");
+    assert_eq!(v6_range.union(&high), Some(v6_range));
+    assert_eq!(high.intersection(&v6_range), Some(high));
+}
+
+#[test]
+fn should_roundtrip_bounds() {
+    let range = IpRange::new(net::IpAddr::from([10, 0, 0, 5]), net::IpAddr::from([10, 0, 1, 10])).expect("valid range");
+    assert_eq!(range.to_bounds(), (0x0a00_0005, 0x0a00_010a));
+    assert_eq!(IpRange::from_bounds(AddrFamily::V4, 0x0a00_0005, 0x0a00_010a), Ok(range));
+
+    let range = IpRange::new(net::IpAddr::V6(net::Ipv6Addr::from_bits(1 << 64)), net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX))).expect("valid range");
+    assert_eq!(range.to_bounds(), (1 << 64, u128::MAX));
+    let (lo, hi) = range.to_bounds();
+    assert_eq!(IpRange::from_bounds(AddrFamily::V6, lo, hi), Ok(range));
+
+    assert_eq!(IpRange::from_bounds(AddrFamily::V4, 0, u32::MAX as u128).map(|range| range.end()), Ok(net::IpAddr::from([255, 255, 255, 255])));
+    assert_eq!(IpRange::from_bounds(AddrFamily::V4, 0, u32::MAX as u128 + 1), Err(RangeError::Overflow));
+    assert_eq!(IpRange::from_bounds(AddrFamily::V6, 5, 4), Err(RangeError::Empty));
+}
+
+#[test]
+fn should_extend_block_to_range() {
+    let block = cidr!("10.0.0.0/24");
+    let tail = IpRange::new(net::IpAddr::from([10, 0, 1, 0]), net::IpAddr::from([10, 0, 1, 9])).expect("valid range");
+    let extended = IpRange::new(net::IpAddr::from([10, 0, 0, 0]), net::IpAddr::from([10, 0, 1, 9])).expect("valid range");
+    assert_eq!(block.extend_to_range(&tail), Some(extended));
+    assert_eq!(tail.union(&IpRange::from(block)), Some(extended));
+
+    let head = IpRange::new(net::IpAddr::from([9, 255, 255, 250]), net::IpAddr::from([10, 0, 0, 20])).expect("valid range");
+    assert_eq!(block.extend_to_range(&head), IpRange::new(net::IpAddr::from([9, 255, 255, 250]), net::IpAddr::from([10, 0, 0, 255])).ok());
+    let inner = IpRange::new(net::IpAddr::from([10, 0, 0, 5]), net::IpAddr::from([10, 0, 0, 20])).expect("valid range");
+    assert_eq!(block.extend_to_range(&inner), Some(IpRange::from(block)));
+
+    let gap = IpRange::new(net::IpAddr::from([10, 0, 1, 1]), net::IpAddr::from([10, 0, 1, 9])).expect("valid range");
+    assert_eq!(block.extend_to_range(&gap), None);
+    assert_eq!(cidr!("::/0").extend_to_range(&tail), None);
+}
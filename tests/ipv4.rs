@@ -71,12 +71,12 @@ fn should_not_parse_ipv4() {
         ("", ParseError::MissingIp),
         ("-1.", ParseError::UnexpectedCharacter('-', 0)),
         ("%1.", ParseError::UnexpectedCharacter('%', 0)),
-        ("0.0.0", ParseError::Ipv4InvalidComponentSize(3)),
-        ("127.0.0.1.5", ParseError::Ipv4InvalidComponentSize(5)),
+        ("0.0.0", ParseError::Ipv4InvalidComponentSize(3, 5)),
+        ("127.0.0.1.5", ParseError::Ipv4InvalidComponentSize(5, 9)),
         ("1..", ParseError::InvalidIpv4),
         ("256.0.0.1", ParseError::InvalidComponent("256")),
         ("1", ParseError::InvalidIp),
-        ("1.1", ParseError::Ipv4InvalidComponentSize(2)),
+        ("1.1", ParseError::Ipv4InvalidComponentSize(2, 3)),
         ("1.f", ParseError::InvalidComponent("f")),
         ("f.1", ParseError::InvalidComponent("f")),
         ("127.0.0.1/33", ParseError::Ipv4CidrPrefixOverflow(33)),
@@ -94,3 +94,779 @@ fn should_not_parse_ipv4() {
         assert_eq!(error, *expected_error);
     }
 }
+
+#[test]
+fn should_match_v4_family_bounds() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 0, 1), 0).expect("to create");
+    let (first, last) = Cidr::family_bounds(ip_cidr::AddrFamily::V4);
+    assert_eq!(first, net::IpAddr::V4(ip_cidr::v4::FIRST));
+    assert_eq!(last, net::IpAddr::V4(ip_cidr::v4::LAST));
+    assert_eq!(cidr.network_addr(), first);
+    assert_eq!(cidr.broadcast_addr(), last);
+    assert_eq!(ip_cidr::v4::FIRST, net::Ipv4Addr::UNSPECIFIED);
+    assert_eq!(ip_cidr::v4::LAST, net::Ipv4Addr::BROADCAST);
+}
+
+#[test]
+fn should_report_v4_prefix_error() {
+    let addr = net::Ipv4Addr::new(10, 0, 0, 0);
+    let error = Cidr::try_new(addr.into(), 40).expect_err("should fail");
+    assert_eq!(error, ip_cidr::PrefixError { prefix: 40, max: 32 });
+    assert_eq!(error.to_string(), "Prefix '40' is greater than 32");
+
+    let cidr = Cidr::try_new(addr.into(), 32).expect("to create");
+    assert_eq!(Some(cidr), Cidr::new(addr.into(), 32));
+}
+
+#[test]
+fn should_report_partially_parsed_ipv4() {
+    let error = ip_cidr::parse_ip_verbose("10.1").expect_err("should fail");
+    assert_eq!(error.error, ParseError::Ipv4InvalidComponentSize(2, 4));
+    assert_eq!(error.family, Some(ip_cidr::AddrFamily::V4));
+    assert_eq!(error.components(), [10, 1]);
+    assert_eq!(error.to_string(), error.error.to_string());
+
+    let error = ip_cidr::parse_ip_verbose("10.1.300.1").expect_err("should fail");
+    assert_eq!(error.error, ParseError::InvalidComponent("300"));
+    assert_eq!(error.family, Some(ip_cidr::AddrFamily::V4));
+    assert_eq!(error.components(), [10, 1]);
+
+    let error = ip_cidr::parse_ip_verbose("").expect_err("should fail");
+    assert_eq!(error.error, ParseError::MissingIp);
+    assert_eq!(error.family, None);
+    assert!(error.components().is_empty());
+
+    let (ip, prefix) = ip_cidr::parse_ip_verbose("10.1.0.1/8").expect("to parse");
+    assert_eq!(ip, net::Ipv4Addr::new(10, 1, 0, 1));
+    assert_eq!(prefix, Some(8));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_create_v4_bitmap() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 20), 28).expect("to create");
+    let mut bitmap = cidr.to_bitmap().expect("to create bitmap");
+    assert_eq!(bitmap.len(), 16);
+    assert_eq!(bitmap.as_words(), [0xffff]);
+    for idx in 0..16 {
+        assert!(bitmap.get(idx));
+    }
+    assert!(!bitmap.get(16));
+
+    assert!(bitmap.set(3, false));
+    assert!(!bitmap.get(3));
+    assert!(!bitmap.set(16, true));
+    assert_eq!(bitmap.as_words(), [0xfff7]);
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 20), 32).expect("to create");
+    assert_eq!(cidr.to_bitmap().expect("to create bitmap").as_words(), [1]);
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 0, 0), ip_cidr::v4::BITMAP_MIN_PREFIX).expect("to create");
+    let bitmap = cidr.to_bitmap().expect("to create bitmap");
+    assert_eq!(bitmap.len(), 65536);
+    assert!(bitmap.as_words().iter().all(|word| *word == u64::MAX));
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 0, 0), ip_cidr::v4::BITMAP_MIN_PREFIX - 1).expect("to create");
+    assert!(cidr.to_bitmap().is_none());
+}
+
+#[test]
+fn should_create_v4_block_at_prefix() {
+    let addr = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 1, 55));
+    let cidr = Cidr::at_prefix(addr, 24).expect("to create");
+    assert_eq!(cidr, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 0), 24).unwrap());
+    assert_eq!(cidr.to_string(), "10.0.1.0/24");
+    assert!(cidr.contains(addr));
+
+    assert_eq!(Cidr::at_prefix(addr, 0), Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0));
+    assert_eq!(Cidr::at_prefix(addr, 32), Cidr::new(addr, 32));
+    assert_eq!(Cidr::at_prefix(addr, 33), None);
+}
+
+#[test]
+fn should_contain_every_v4_address_in_default_route() {
+    for addr in [net::Ipv4Addr::UNSPECIFIED, net::Ipv4Addr::new(192, 168, 0, 1), net::Ipv4Addr::BROADCAST] {
+        let cidr = Cidr::new_v4(addr, 0).expect("to create");
+        for addr in [net::Ipv4Addr::UNSPECIFIED, net::Ipv4Addr::new(0, 0, 0, 1), net::Ipv4Addr::new(127, 255, 255, 255), net::Ipv4Addr::new(128, 0, 0, 0), net::Ipv4Addr::new(255, 255, 255, 254), net::Ipv4Addr::BROADCAST] {
+            assert!(cidr.contains(addr.into()), "{addr} is not contained in cidr={cidr}");
+        }
+
+        assert!(!cidr.contains(net::Ipv6Addr::UNSPECIFIED.into()));
+        assert!(!cidr.contains(addr.to_ipv6_mapped().into()));
+        assert!(!cidr.contains(net::Ipv6Addr::from_bits(u128::MAX).into()));
+    }
+}
+
+#[test]
+fn should_report_ipv4_component_count_position() {
+    let inputs = [
+        ("1.2", ParseError::Ipv4InvalidComponentSize(2, 3), "IPv4 Address has '2' components but expected 4, missing separator at idx=3 (shorthand notation is not supported)"),
+        ("1.2/8", ParseError::Ipv4InvalidComponentSize(2, 3), "IPv4 Address has '2' components but expected 4, missing separator at idx=3 (shorthand notation is not supported)"),
+        ("1.2.3", ParseError::Ipv4InvalidComponentSize(3, 5), "IPv4 Address has '3' components but expected 4, missing separator at idx=5 (shorthand notation is not supported)"),
+        ("10.20.30/24", ParseError::Ipv4InvalidComponentSize(3, 8), "IPv4 Address has '3' components but expected 4, missing separator at idx=8 (shorthand notation is not supported)"),
+        ("1.2.3.4.5", ParseError::Ipv4InvalidComponentSize(5, 7), "IPv4 Address has '5' components but expected 4, extra separator at idx=7"),
+        ("10.20.30.40.50/8", ParseError::Ipv4InvalidComponentSize(5, 11), "IPv4 Address has '5' components but expected 4, extra separator at idx=11"),
+    ];
+
+    for (text, expected_error, expected_message) in inputs {
+        println!("Parse '{text}'");
+        let error = ip_cidr::parse_ip(text).expect_err("should fail");
+        assert_eq!(error, expected_error);
+        assert_eq!(error.to_string(), expected_message);
+    }
+}
+
+#[test]
+fn should_validate_v4_prefix() {
+    assert!(ip_cidr::v4::is_valid_prefix(0));
+    assert!(ip_cidr::v4::is_valid_prefix(32));
+    assert!(!ip_cidr::v4::is_valid_prefix(33));
+    assert!(Cidr::is_valid_prefix(ip_cidr::AddrFamily::V4, 32));
+    assert!(!Cidr::is_valid_prefix(ip_cidr::AddrFamily::V4, 33));
+}
+
+#[test]
+fn should_parse_exact_ipv4_cidr() {
+    let cidr = ip_cidr::parse_cidr_exact("10.0.0.0/8").expect("to parse");
+    assert_eq!(cidr, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap());
+    let cidr = ip_cidr::parse_cidr_exact("10.0.0.1").expect("to parse");
+    assert_eq!(cidr, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 32).unwrap());
+
+    let inputs = [
+        ("10.0.0.0/8x", ParseError::InvalidCidr("8x")),
+        ("10.0.0.0/8/8", ParseError::InvalidCidr("8/8")),
+        ("10.0.0.0/8 ", ParseError::TrailingWhitespace(10)),
+        ("10.0.0.0x/8", ParseError::UnexpectedCharacter('x', 8)),
+        ("10.0.0.0 /8", ParseError::UnexpectedCharacter(' ', 8)),
+        ("10.0.0.0/", ParseError::MissingCidr),
+        ("10.0.0.0/33", ParseError::Ipv4CidrPrefixOverflow(33)),
+        ("10.0.0.0/255", ParseError::Ipv4CidrPrefixOverflow(255)),
+    ];
+
+    for (text, expected_error) in inputs {
+        println!("Parse '{text}'");
+        assert_eq!(ip_cidr::parse_cidr_exact(text).expect_err("should fail"), expected_error);
+    }
+}
+
+#[test]
+fn should_display_v4_styles() {
+    use ip_cidr::DisplayStyle;
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 0), 24).expect("to create");
+    assert_eq!(cidr.display_style(DisplayStyle::Canonical).to_string(), "192.168.1.0/24");
+    assert_eq!(cidr.display_style(DisplayStyle::Netmask).to_string(), "192.168.1.0 255.255.255.0");
+    assert_eq!(cidr.display_style(DisplayStyle::Wildcard).to_string(), "192.168.1.0 0.0.0.255");
+    assert_eq!(cidr.display_style(DisplayStyle::Expanded).to_string(), "192.168.1.0/24");
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 0).expect("to create");
+    assert_eq!(cidr.display_style(DisplayStyle::Netmask).to_string(), "10.0.0.1 0.0.0.0");
+    assert_eq!(cidr.display_style(DisplayStyle::Wildcard).to_string(), "10.0.0.1 255.255.255.255");
+}
+
+#[test]
+fn should_return_v4_default_gateway() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 77), 24).expect("to create");
+    assert_eq!(cidr.default_gateway(), Some(net::Ipv4Addr::new(192, 168, 1, 1)));
+    assert!(cidr.is_default_gateway(net::Ipv4Addr::new(192, 168, 1, 1)));
+    assert!(!cidr.is_default_gateway(net::Ipv4Addr::new(192, 168, 1, 77)));
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 7), 30).expect("to create");
+    assert_eq!(cidr.default_gateway(), Some(net::Ipv4Addr::new(10, 0, 0, 5)));
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 7), 31).expect("to create");
+    assert_eq!(cidr.default_gateway(), Some(net::Ipv4Addr::new(10, 0, 0, 6)));
+
+    let cidr = ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::new(10, 0, 0, 7));
+    assert_eq!(cidr.default_gateway(), None);
+    assert!(!cidr.is_default_gateway(net::Ipv4Addr::new(10, 0, 0, 7)));
+}
+
+#[test]
+fn should_check_v4_addr_in_cidr_text() {
+    const IS_PRIVATE: Result<bool, ParseError<'static>> = ip_cidr::addr_in_cidr_str(net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 5)), "192.168.1.0/24");
+    assert_eq!(IS_PRIVATE, Ok(true));
+
+    let addr = net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 2, 5));
+    assert_eq!(ip_cidr::addr_in_cidr_str(addr, "192.168.1.0/24"), Ok(false));
+    assert_eq!(ip_cidr::addr_in_cidr_str(addr, "::/0"), Ok(false));
+    assert_eq!(ip_cidr::addr_in_cidr_str(addr, "192.168.1.0/33"), Err(ParseError::Ipv4CidrPrefixOverflow(33)));
+    assert_eq!(ip_cidr::addr_in_cidr_str(addr, "192.168.1/24"), Err(ParseError::Ipv4InvalidComponentSize(3, 9)));
+}
+
+#[test]
+fn should_check_v4_canonical() {
+    assert!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 0), 24).unwrap().is_canonical());
+    assert!(!Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 5), 24).unwrap().is_canonical());
+    assert!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 5), 32).unwrap().is_canonical());
+    assert!(Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().is_canonical());
+    assert!(!Cidr::new_v4(net::Ipv4Addr::BROADCAST, 0).unwrap().is_canonical());
+}
+
+#[test]
+fn should_check_v4_subnet_alignment() {
+    let parent = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 16).unwrap();
+    assert!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 4, 0), 22).unwrap().aligns_within(&parent));
+    assert!(parent.aligns_within(&parent));
+    //Not on /22 boundary
+    assert!(!Cidr::new_v4(net::Ipv4Addr::new(10, 0, 2, 0), 22).unwrap().aligns_within(&parent));
+    //Wider than parent
+    assert!(!Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 15).unwrap().aligns_within(&parent));
+    //Outside of parent
+    assert!(!Cidr::new_v4(net::Ipv4Addr::new(10, 1, 0, 0), 24).unwrap().aligns_within(&parent));
+    assert!(!Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 128).unwrap().aligns_within(&parent));
+}
+
+#[test]
+fn should_create_v4_from_host_bits() {
+    let addr = net::Ipv4Addr::new(192, 168, 1, 0);
+    assert_eq!(ip_cidr::v4::Cidr::from_host_bits(addr, 8), ip_cidr::v4::Cidr::new(addr, 24));
+    assert_eq!(ip_cidr::v4::Cidr::from_host_bits(addr, 0), ip_cidr::v4::Cidr::new(addr, 32));
+    assert_eq!(ip_cidr::v4::Cidr::from_host_bits(addr, 32), ip_cidr::v4::Cidr::new(addr, 0));
+    assert_eq!(ip_cidr::v4::Cidr::from_host_bits(addr, 33), None);
+}
+
+#[test]
+fn should_report_trailing_whitespace() {
+    let inputs = [
+        ("10.0.0.0/8\n", ParseError::TrailingWhitespace(10)),
+        ("10.0.0.0/8\r\n", ParseError::TrailingWhitespace(10)),
+        ("10.0.0.1 \t", ParseError::TrailingWhitespace(8)),
+        ("10.0.0.1\n", ParseError::TrailingWhitespace(8)),
+        ("::1\n", ParseError::TrailingWhitespace(3)),
+        ("::/0\n", ParseError::TrailingWhitespace(4)),
+        //Other problems take priority
+        ("10.0.0.0/33\n", ParseError::Ipv4CidrPrefixOverflow(33)),
+        ("10.0.0.0/x\n", ParseError::InvalidCidr("x\n")),
+        ("10.0.0.0/ \n", ParseError::InvalidCidr(" \n")),
+        ("10.0.0\n", ParseError::Ipv4InvalidComponentSize(3, 6)),
+        ("10.0.0.0 /8", ParseError::UnexpectedCharacter(' ', 8)),
+        ("10.0.0.0 x", ParseError::UnexpectedCharacter(' ', 8)),
+        (" 10.0.0.0", ParseError::UnexpectedCharacter(' ', 0)),
+    ];
+
+    for (text, expected_error) in inputs {
+        println!("Parse '{}'", text.escape_debug());
+        assert_eq!(ip_cidr::parse_ip(text).expect_err("should fail"), expected_error);
+    }
+
+    let error = ip_cidr::parse_cidr("10.0.0.0/8\n").expect_err("should fail");
+    assert_eq!(error.to_string(), "Encountered trailing whitespace at idx=10, input must be trimmed");
+}
+
+#[test]
+fn should_split_v4_into_halves() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 77), 24).unwrap();
+    let [low, high] = cidr.halves().expect("to split");
+    assert_eq!(low, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 25).unwrap());
+    assert_eq!(high, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 128), 25).unwrap());
+    assert!(low.aligns_within(&cidr));
+    assert!(high.aligns_within(&cidr));
+    //Halves cover the whole block without gaps
+    assert_eq!(low.network_addr(), cidr.network_addr());
+    assert_eq!(high.broadcast_addr(), cidr.broadcast_addr());
+    assert_eq!(low.size() + high.size(), cidr.size());
+
+    let [low, high] = Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().halves().expect("to split");
+    assert_eq!(low.to_string(), "0.0.0.0/1");
+    assert_eq!(high.to_string(), "128.0.0.0/1");
+
+    let [low, high] = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 31).unwrap().halves().expect("to split");
+    assert_eq!(low.to_string(), "10.0.0.0/32");
+    assert_eq!(high.to_string(), "10.0.0.1/32");
+
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::BROADCAST, 32).unwrap().halves(), None);
+}
+
+#[test]
+fn should_compute_v4_even_split_prefix() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+    assert_eq!(cidr.even_split_prefix(5), Some(27));
+    assert_eq!(cidr.even_split_prefix(8), Some(27));
+    assert_eq!(cidr.even_split_prefix(9), Some(28));
+    assert_eq!(cidr.even_split_prefix(1), Some(24));
+    assert_eq!(cidr.even_split_prefix(256), Some(32));
+    assert_eq!(cidr.even_split_prefix(257), None);
+    assert_eq!(cidr.even_split_prefix(0), None);
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(cidr.even_split_prefix(u32::MAX), Some(32));
+    assert_eq!(Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap().even_split_prefix(u32::MAX), Some(32));
+    assert_eq!(Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap().even_split_prefix(2), None);
+}
+
+#[test]
+fn should_summarize_v4_block() {
+    let summary = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 37), 28).unwrap().summary();
+    assert_eq!(summary, ip_cidr::BlockSummary {
+        network: net::Ipv4Addr::new(192, 168, 1, 32).into(),
+        broadcast: net::Ipv4Addr::new(192, 168, 1, 47).into(),
+        first_host: net::Ipv4Addr::new(192, 168, 1, 33).into(),
+        last_host: net::Ipv4Addr::new(192, 168, 1, 46).into(),
+        usable_hosts: 14,
+        size: 16,
+        netmask: net::Ipv4Addr::new(255, 255, 255, 240).into(),
+        wildcard: net::Ipv4Addr::new(0, 0, 0, 15).into(),
+    });
+
+    let summary = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 31).unwrap().summary();
+    assert_eq!(summary.first_host, net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 0)));
+    assert_eq!(summary.last_host, net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1)));
+    assert_eq!(summary.usable_hosts, 2);
+
+    let summary = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 32).unwrap().summary();
+    assert_eq!(summary.first_host, summary.last_host);
+    assert_eq!(summary.usable_hosts, 1);
+    assert_eq!(summary.wildcard, net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED));
+
+    let summary = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 0).unwrap().summary();
+    assert_eq!(summary.first_host, net::IpAddr::V4(net::Ipv4Addr::new(0, 0, 0, 1)));
+    assert_eq!(summary.last_host, net::IpAddr::V4(net::Ipv4Addr::new(255, 255, 255, 254)));
+    assert_eq!(summary.usable_hosts, 4294967294);
+}
+
+#[test]
+fn should_iterate_v4_addresses() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 5), 30).unwrap();
+    let mut iter = cidr.iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(net::Ipv4Addr::new(10, 0, 0, 4).into()));
+    assert_eq!(iter.next_back(), Some(net::Ipv4Addr::new(10, 0, 0, 7).into()));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(net::Ipv4Addr::new(10, 0, 0, 6).into()));
+    assert_eq!(iter.next(), Some(net::Ipv4Addr::new(10, 0, 0, 5).into()));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 0), 28).unwrap();
+    for (idx, addr) in cidr.iter().enumerate() {
+        assert_eq!(cidr.get(idx as u32), Some(addr));
+    }
+    assert_eq!(cidr.iter().next_back(), Some(cidr.broadcast_addr()));
+
+    //Does not wrap past the highest address
+    let cidr = ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::BROADCAST);
+    assert_eq!(cidr.iter().collect::<Vec<_>>(), [net::Ipv4Addr::BROADCAST]);
+
+    let mut iter = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().iter();
+    assert_eq!(iter.len(), (u32::MAX as usize).saturating_add(1));
+    assert_eq!(iter.next_back(), Some(net::Ipv4Addr::BROADCAST));
+    assert_eq!(iter.next(), Some(net::Ipv4Addr::UNSPECIFIED));
+}
+
+#[test]
+fn should_iterate_v4_hosts() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 0), 29).unwrap();
+    let hosts = cidr.hosts();
+    assert_eq!(hosts.len(), 6);
+    assert_eq!(hosts.len() as u128, cidr.summary().usable_hosts);
+    let hosts = hosts.map(|addr| addr.to_string()).collect::<Vec<_>>();
+    assert_eq!(hosts, ["192.168.1.1", "192.168.1.2", "192.168.1.3", "192.168.1.4", "192.168.1.5", "192.168.1.6"]);
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 0), 30).unwrap();
+    assert_eq!(cidr.hosts().collect::<Vec<_>>(), [
+        net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 1)),
+        net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 2)),
+    ]);
+    assert_eq!(cidr.hosts().next_back(), Some(net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 2))));
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 1), 31).unwrap();
+    assert_eq!(cidr.hosts().collect::<Vec<_>>(), [net::Ipv4Addr::new(10, 0, 0, 0), net::Ipv4Addr::new(10, 0, 0, 1)]);
+    let cidr = ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::BROADCAST);
+    assert_eq!(cidr.hosts().collect::<Vec<_>>(), [net::Ipv4Addr::BROADCAST]);
+
+    let mut hosts = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().hosts();
+    assert_eq!(hosts.next(), Some(net::Ipv4Addr::new(0, 0, 0, 1)));
+    assert_eq!(hosts.next_back(), Some(net::Ipv4Addr::new(255, 255, 255, 254)));
+}
+
+#[test]
+fn should_propagate_parse_error_as_std_error() {
+    fn parse(text: &str) -> Result<Cidr, Box<dyn std::error::Error + '_>> {
+        let cidr = ip_cidr::parse_cidr_exact(text)?;
+        Ok(cidr)
+    }
+
+    assert_eq!(parse("10.0.0.0/8").expect("to parse"), Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap());
+    let error = parse("10.0.0.0/33").expect_err("should fail");
+    assert_eq!(error.to_string(), "Prefix '33' is greater than 32");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn should_check_v4_cidr_containment() {
+    let parent = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    assert!(parent.contains_cidr(&Cidr::new_v4(net::Ipv4Addr::new(10, 1, 2, 0), 24).unwrap()));
+    assert!(parent.contains_cidr(&parent));
+    //Host bits are ignored
+    assert!(parent.contains_cidr(&Cidr::new_v4(net::Ipv4Addr::new(10, 1, 2, 3), 24).unwrap()));
+    assert!(Cidr::new_v4(net::Ipv4Addr::new(10, 1, 2, 3), 8).unwrap().contains_cidr(&parent));
+    assert!(!parent.contains_cidr(&Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 7).unwrap()));
+    assert!(!parent.contains_cidr(&Cidr::new_v4(net::Ipv4Addr::new(11, 0, 0, 0), 24).unwrap()));
+    assert!(Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().contains_cidr(&parent));
+    assert!(!parent.contains_cidr(&Cidr::new_v6(net::Ipv4Addr::new(10, 0, 0, 0).to_ipv6_mapped(), 128).unwrap()));
+    assert!(!Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap().contains_cidr(&parent));
+}
+
+#[test]
+fn should_check_v4_overlap() {
+    let low = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 25).unwrap();
+    let high = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 128), 25).unwrap();
+    let parent = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+    //Adjacent blocks are disjoint
+    assert!(!low.overlaps(&high));
+    assert!(!high.overlaps(&low));
+    assert!(low.overlaps(&parent));
+    assert!(parent.overlaps(&high));
+    assert!(low.overlaps(&low));
+    assert!(!parent.overlaps(&Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 0), 24).unwrap()));
+    assert!(!parent.overlaps(&Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap()));
+}
+
+#[test]
+fn should_split_v4_into_subnets() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 7), 24).unwrap();
+    let subnets = cidr.subnets(26).expect("to split");
+    assert_eq!(subnets.len(), 4);
+    let subnets = subnets.map(|subnet| subnet.to_string()).collect::<Vec<_>>();
+    assert_eq!(subnets, ["10.0.0.0/26", "10.0.0.64/26", "10.0.0.128/26", "10.0.0.192/26"]);
+
+    let mut subnets = cidr.subnets(26).expect("to split");
+    assert_eq!(subnets.next_back(), Some(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 192), 26).unwrap()));
+    assert_eq!(subnets.len(), 3);
+
+    assert_eq!(cidr.subnets(24).expect("to split").collect::<Vec<_>>(), [Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap()]);
+    assert_eq!(cidr.subnets(32).expect("to split").len(), 256);
+    assert!(cidr.subnets(23).is_none());
+    assert!(cidr.subnets(33).is_none());
+
+    let all = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(all.subnets(0).expect("to split").len(), 1);
+    let mut subnets = all.subnets(32).expect("to split");
+    assert_eq!(subnets.len(), (u32::MAX as usize).saturating_add(1));
+    assert_eq!(subnets.next_back(), Some(ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::BROADCAST)));
+    let mut subnets = all.subnets(1).expect("to split");
+    assert_eq!(subnets.next().map(|subnet| subnet.to_string()).as_deref(), Some("0.0.0.0/1"));
+    assert_eq!(subnets.next().map(|subnet| subnet.to_string()).as_deref(), Some("128.0.0.0/1"));
+    assert_eq!(subnets.next(), None);
+}
+
+#[test]
+fn should_widen_v4_to_supernet() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 128), 25).unwrap();
+    let supernet = cidr.supernet().expect("to widen");
+    assert_eq!(supernet.addr(), net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 0)));
+    assert_eq!(supernet.prefix(), 24);
+    assert!(supernet.contains_cidr(&cidr));
+
+    let supernet = cidr.supernet_at(16).expect("to widen");
+    assert_eq!(supernet.to_string(), "10.0.0.0/16");
+    assert_eq!(cidr.supernet_at(25), Some(cidr));
+    assert_eq!(cidr.supernet_at(26), None);
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 1), 32).unwrap().supernet_at(0).unwrap().to_string(), "0.0.0.0/0");
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 1), 0).unwrap().supernet(), None);
+}
+
+#[test]
+fn should_parse_v4_dotted_netmask() {
+    let inputs = [
+        ("192.168.1.0/255.255.255.0", 24),
+        ("0.0.0.0/0.0.0.0", 0),
+        ("10.0.0.1/255.255.255.255", 32),
+        ("10.0.0.0/255.128.0.0", 9),
+        ("10.0.0.0/255.255.255.254", 31),
+    ];
+
+    for (text, expected_prefix) in inputs {
+        let (_, prefix) = ip_cidr::parse_ip(text).expect("to parse");
+        assert_eq!(prefix, Some(expected_prefix), "'{text}' has unexpected prefix");
+    }
+
+    let cidr = ip_cidr::parse_cidr_exact("192.168.1.0/255.255.255.0").expect("to parse");
+    assert_eq!(cidr.to_string(), "192.168.1.0/24");
+
+    let error = ip_cidr::parse_ip("10.0.0.0/255.0.255.0").expect_err("should fail");
+    assert_eq!(error, ParseError::NonContiguousNetmask(net::Ipv4Addr::new(255, 0, 255, 0)));
+    assert_eq!(error.to_string(), "Netmask '255.0.255.0' is not contiguous");
+    assert_eq!(ip_cidr::parse_ip("10.0.0.0/0.0.0.255").expect_err("should fail"), ParseError::NonContiguousNetmask(net::Ipv4Addr::new(0, 0, 0, 255)));
+    assert_eq!(ip_cidr::parse_ip("10.0.0.0/255.255.0").expect_err("should fail"), ParseError::InvalidCidr("255.255.0"));
+    assert_eq!(ip_cidr::parse_ip("10.0.0.0/255.255.255.0/8").expect_err("should fail"), ParseError::InvalidCidr("255.255.255.0/8"));
+    assert_eq!(ip_cidr::parse_ip("10.0.0.0/ffff::").expect_err("should fail"), ParseError::InvalidCidr("ffff::"));
+    assert_eq!(ip_cidr::parse_ip("10.0.0.0/255.255.255.0\n").expect_err("should fail"), ParseError::TrailingWhitespace(22));
+    //Netmask is not supported for IPv6
+    assert_eq!(ip_cidr::parse_ip("::/255.255.255.0").expect_err("should fail"), ParseError::InvalidCidr("255.255.255.0"));
+}
+
+#[test]
+fn should_return_v4_netmask() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 7), 24).unwrap();
+    assert_eq!(cidr.netmask(), net::IpAddr::V4(net::Ipv4Addr::new(255, 255, 255, 0)));
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 9).unwrap().netmask().to_string(), "255.128.0.0");
+    assert_eq!(ip_cidr::v4::Cidr::new(net::Ipv4Addr::BROADCAST, 0).unwrap().netmask(), net::Ipv4Addr::UNSPECIFIED);
+    assert_eq!(ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 32).unwrap().netmask(), net::Ipv4Addr::BROADCAST);
+}
+
+#[test]
+fn should_return_v4_wildcard_mask() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 1, 7), 24).unwrap();
+    assert_eq!(cidr.wildcard_mask(), net::IpAddr::V4(net::Ipv4Addr::new(0, 0, 0, 255)));
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 9).unwrap().wildcard_mask().to_string(), "0.127.255.255");
+    assert_eq!(ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().wildcard_mask(), net::Ipv4Addr::BROADCAST);
+    assert_eq!(ip_cidr::v4::Cidr::new(net::Ipv4Addr::BROADCAST, 32).unwrap().wildcard_mask(), net::Ipv4Addr::UNSPECIFIED);
+}
+
+#[test]
+fn should_classify_v4_network_addr() {
+    let private = ["10.1.0.0/16", "172.16.0.0/12", "172.31.255.0/24", "192.168.1.1/24"];
+    for text in private {
+        assert!(ip_cidr::parse_cidr_exact(text).unwrap().is_private(), "'{text}' should be private");
+    }
+    assert!(!ip_cidr::parse_cidr_exact("172.32.0.0/16").unwrap().is_private());
+    //Only network address is considered
+    assert!(!ip_cidr::parse_cidr_exact("10.0.0.0/6").unwrap().is_private());
+
+    assert!(ip_cidr::parse_cidr_exact("127.0.0.1/8").unwrap().is_loopback());
+    assert!(!ip_cidr::parse_cidr_exact("128.0.0.1/8").unwrap().is_loopback());
+    assert!(ip_cidr::parse_cidr_exact("224.0.0.0/4").unwrap().is_multicast());
+    assert!(!ip_cidr::parse_cidr_exact("192.0.0.0/4").unwrap().is_multicast());
+    assert!(ip_cidr::parse_cidr_exact("1.2.3.4/0").unwrap().is_unspecified());
+    assert!(!ip_cidr::parse_cidr_exact("128.2.3.4/1").unwrap().is_unspecified());
+}
+
+#[test]
+fn should_detect_v4_block_straddling_reserved() {
+    //Covers 0.0.0.0/8, 10.0.0.0/8, 100.64.0.0/10 and 127.0.0.0/8 together with global space
+    assert!(ip_cidr::cidr!("0.0.0.0/1").straddles_reserved());
+    assert!(ip_cidr::cidr!("192.168.0.0/15").straddles_reserved());
+    assert!(ip_cidr::cidr!("192.0.2.0/23").straddles_reserved());
+    assert!(ip_cidr::cidr!("0.0.0.0/0").straddles_reserved());
+
+    //Fully reserved
+    assert!(!ip_cidr::cidr!("10.1.0.0/16").straddles_reserved());
+    assert!(!ip_cidr::cidr!("240.0.0.0/4").straddles_reserved());
+    assert!(!ip_cidr::cidr!("255.255.255.255/32").straddles_reserved());
+    //Fully global
+    assert!(!ip_cidr::cidr!("8.8.8.0/24").straddles_reserved());
+    assert!(!ip_cidr::cidr!("11.0.0.0/8").straddles_reserved());
+}
+
+#[test]
+fn should_return_v4_range() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 1), 30).unwrap();
+    assert_eq!(cidr.range(), (net::Ipv4Addr::new(10, 0, 0, 0), net::Ipv4Addr::new(10, 0, 0, 3)));
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 32).unwrap();
+    assert_eq!(cidr.range(), (cidr.addr(), cidr.addr()));
+}
+
+#[test]
+fn should_find_v4_index_of_addr() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+    assert_eq!(cidr.index_of(net::Ipv4Addr::new(10, 0, 0, 5).into()), Some(5));
+    assert_eq!(cidr.index_of(net::Ipv4Addr::new(10, 0, 0, 0).into()), Some(0));
+    assert_eq!(cidr.index_of(net::Ipv4Addr::new(10, 0, 0, 255).into()), Some(255));
+    assert_eq!(cidr.index_of(net::Ipv4Addr::new(10, 0, 1, 0).into()), None);
+    assert_eq!(cidr.index_of(net::Ipv4Addr::new(10, 0, 0, 5).to_ipv6_mapped().into()), None);
+    for idx in [0, 1, 100, 255] {
+        assert_eq!(cidr.index_of(cidr.get(idx).unwrap()), Some(idx));
+    }
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(cidr.index_of(net::Ipv4Addr::BROADCAST), Some(u32::MAX));
+}
+
+#[test]
+fn should_step_to_adjacent_v4_blocks() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+    assert_eq!(cidr.next().unwrap().to_string(), "10.0.1.0/24");
+    assert_eq!(cidr.prev().unwrap().to_string(), "9.255.255.0/24");
+    assert_eq!(cidr.next().unwrap().prev(), Some(cidr));
+    //Host bits are cleared
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 7), 24).unwrap().next().unwrap().to_string(), "10.0.1.0/24");
+
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(255, 255, 255, 0), 24).unwrap().next(), None);
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(0, 0, 0, 255), 24).unwrap().prev(), None);
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::BROADCAST, 32).unwrap().next(), None);
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::BROADCAST, 32).unwrap().prev().unwrap().to_string(), "255.255.255.254/32");
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 1).unwrap().next().unwrap().to_string(), "128.0.0.0/1");
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().next(), None);
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::UNSPECIFIED, 0).unwrap().prev(), None);
+}
+
+#[test]
+fn should_get_prefix_from_netmask() {
+    use ip_cidr::v4::prefix_from_netmask;
+
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::new(255, 255, 255, 0)), Some(24));
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::new(255, 255, 128, 0)), Some(17));
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::UNSPECIFIED), Some(0));
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::BROADCAST), Some(32));
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::new(255, 0, 255, 0)), None);
+    assert_eq!(prefix_from_netmask(net::Ipv4Addr::new(0, 0, 0, 255)), None);
+
+    const PREFIX: Option<u8> = prefix_from_netmask(net::Ipv4Addr::new(255, 255, 255, 252));
+    assert_eq!(PREFIX, Some(30));
+}
+
+#[test]
+fn should_format_ipv4_as_hex_and_binary() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 0, 0), 24).unwrap();
+    let Cidr::V4(cidr) = cidr else { unreachable!() };
+    assert_eq!(format!("{cidr:x}"), "c0a80000/24");
+    assert_eq!(format!("{cidr:#x}"), "0xc0a80000/24");
+    assert_eq!(format!("{cidr:b}"), "11000000101010000000000000000000/24");
+    assert_eq!(format!("{cidr:#b}"), "0b11000000101010000000000000000000/24");
+    assert_eq!(format!("{cidr}"), "192.168.0.0/24");
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(0, 0, 0, 1), 32).unwrap();
+    assert_eq!(format!("{cidr:x}"), "00000001/32");
+}
+
+#[test]
+fn should_count_ipv4_hosts() {
+    let count = |prefix| Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), prefix).unwrap().host_count();
+    assert_eq!(count(24), 254);
+    assert_eq!(count(30), 2);
+    assert_eq!(count(31), 2);
+    assert_eq!(count(32), 1);
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 0), 16).unwrap();
+    assert_eq!(cidr.host_count(), 65534);
+    assert_eq!(cidr.host_count() as usize, cidr.hosts().count());
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(cidr.host_count(), 4294967294);
+    assert_eq!(cidr.host_count() as usize, cidr.hosts().len());
+    assert_eq!(count(0), 4294967294);
+}
+
+#[test]
+fn should_convert_ipv4_with_try_from() {
+    let addr = net::Ipv4Addr::new(10, 0, 0, 0);
+    let cidr = ip_cidr::v4::Cidr::try_from((addr, 8)).expect("to convert");
+    assert_eq!(cidr, ip_cidr::v4::Cidr::new(addr, 8).unwrap());
+    let error = ip_cidr::v4::Cidr::try_from((addr, 33)).unwrap_err();
+    assert_eq!(error, ip_cidr::PrefixError { prefix: 33, max: 32 });
+
+    let cidr = Cidr::try_from((net::IpAddr::V4(addr), 8)).expect("to convert");
+    assert_eq!(cidr, Cidr::new_v4(addr, 8).unwrap());
+    let error = Cidr::try_from((net::IpAddr::V4(addr), 33)).unwrap_err();
+    assert_eq!(error, ip_cidr::PrefixError { prefix: 33, max: 32 });
+
+    assert_eq!(Cidr::try_from("10.0.0.0/8"), Ok(cidr));
+    assert_eq!(Cidr::try_from("10.0.0.0/33"), Err(ParseError::Ipv4CidrPrefixOverflow(33)));
+    assert_eq!(Cidr::try_from("10.0.x.0"), Err(ParseError::UnexpectedCharacter('x', 5)));
+}
+
+#[test]
+fn should_map_ipv4_into_ipv6() {
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    let mapped = cidr.to_ipv6_mapped();
+    assert_eq!(mapped, ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0x0a00, 0), 104).unwrap());
+    assert_eq!(mapped.to_string(), "::ffff:10.0.0.0/104");
+    assert_eq!(mapped.to_ipv4(), Some(cidr));
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(192, 168, 1, 7), 0).unwrap();
+    assert_eq!(cidr.to_ipv6_mapped().prefix(), 96);
+    assert_eq!(cidr.to_ipv6_mapped().to_ipv4(), Some(cidr));
+    let cidr = ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::new(192, 168, 1, 7));
+    assert_eq!(cidr.to_ipv6_mapped().prefix(), 128);
+    assert_eq!(cidr.to_ipv6_mapped().to_ipv4(), Some(cidr));
+}
+
+#[test]
+fn should_split_v4() {
+    const SPLIT: Option<(Cidr, Cidr)> = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap().split();
+    let (low, high) = SPLIT.expect("to split");
+    assert_eq!(low, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 25).unwrap());
+    assert_eq!(high, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 128), 25).unwrap());
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::new(10, 0, 0, 77), 24).unwrap();
+    let (low, high) = cidr.split().expect("to split");
+    assert_eq!([low, high], cidr.halves().unwrap());
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::BROADCAST, 32).unwrap().split(), None);
+}
+
+#[test]
+fn should_compare_ipv4_networks_ignoring_host_bits() {
+    let left = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 5), 24).unwrap();
+    let right = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 9), 24).unwrap();
+    assert_ne!(left, right);
+    assert!(left.same_network(&right));
+    assert_eq!(left.canonical(), right.canonical());
+    assert_eq!(left.canonical(), Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap());
+    assert!(left.canonical().is_canonical());
+
+    assert!(!left.same_network(&Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 5), 25).unwrap()));
+    assert!(!left.same_network(&Cidr::new_v4(net::Ipv4Addr::new(10, 0, 1, 5), 24).unwrap()));
+    assert!(!left.same_network(&Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 24).unwrap()));
+
+    let Cidr::V4(left) = left else { unreachable!() };
+    let Cidr::V4(right) = right else { unreachable!() };
+    assert!(left.same_network(&right));
+    assert_eq!(left.canonical().addr(), net::Ipv4Addr::new(10, 0, 0, 0));
+}
+
+#[test]
+fn should_construct_ipv4_network() {
+    let cidr = Cidr::new_network_v4(net::Ipv4Addr::new(10, 0, 0, 9), 24).unwrap();
+    assert_eq!(cidr.addr(), net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 0)));
+    assert_eq!(cidr, Cidr::new_network_v4(net::Ipv4Addr::new(10, 0, 0, 200), 24).unwrap());
+    assert_eq!(cidr, Cidr::new_network(net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1)), 24).unwrap());
+    assert!(cidr.is_canonical());
+    assert!(Cidr::new_network_v4(net::Ipv4Addr::new(10, 0, 0, 9), 33).is_none());
+
+    let cidr = ip_cidr::v4::Cidr::new_network(net::Ipv4Addr::new(10, 0, 0, 9), 24).unwrap();
+    assert_eq!(cidr.addr(), net::Ipv4Addr::new(10, 0, 0, 0));
+    assert_ne!(Cidr::V4(cidr), Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 9), 24).unwrap());
+}
+
+#[test]
+fn should_compute_ipv4_address_distance() {
+    use ip_cidr::address_distance;
+
+    let left = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1));
+    let right = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 1, 0));
+    assert_eq!(address_distance(left, right), Some(255));
+    assert_eq!(address_distance(right, left), Some(255));
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+    assert_eq!(address_distance(cidr.network_addr(), cidr.broadcast_addr()), Some(cidr.size() - 1));
+    assert_eq!(address_distance(net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED), net::IpAddr::V4(net::Ipv4Addr::BROADCAST)), Some(u32::MAX as u128));
+}
+
+#[test]
+fn should_divide_ipv4_into_count() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+    let subnets = cidr.divide_into(4).expect("to divide").collect::<Vec<_>>();
+    assert_eq!(subnets, [
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 26).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 64), 26).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 128), 26).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 192), 26).unwrap(),
+    ]);
+    assert_eq!(cidr.divide_into(3).expect("to divide").collect::<Vec<_>>(), subnets);
+    assert_eq!(cidr.divide_into(1).expect("to divide").collect::<Vec<_>>(), [cidr]);
+    assert_eq!(cidr.divide_into(256).expect("to divide").len(), 256);
+    assert!(cidr.divide_into(257).is_none());
+    assert!(cidr.divide_into(0).is_none());
+
+    let cidr = ip_cidr::v4::Cidr::new(net::Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(cidr.divide_into(u32::MAX).expect("to divide").len() as u64, 1 << 32);
+}
+
+#[test]
+fn should_parse_trimmed_ipv4() {
+    use ip_cidr::{parse_cidr, parse_cidr_trimmed};
+
+    let expected = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    assert_eq!(parse_cidr_trimmed(" 10.0.0.0/8 "), Ok(Some(expected)));
+    assert_eq!(parse_cidr_trimmed("\t\n10.0.0.0/8\r\n"), Ok(Some(expected)));
+    assert_eq!(parse_cidr_trimmed("10.0.0.0/8"), Ok(Some(expected)));
+    assert_eq!(parse_cidr_trimmed("  10.0.0.1"), Ok(Some(Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 1), 32).unwrap())));
+    assert_eq!(parse_cidr_trimmed(" 10.0.0.0/33 "), parse_cidr("10.0.0.0/33"));
+    assert!(parse_cidr(" 10.0.0.0/8 ").is_err());
+
+    assert_eq!(parse_cidr_trimmed("  10.0. 0.0/8 "), Err(ParseError::UnexpectedCharacter(' ', 7)));
+    assert_eq!(parse_cidr_trimmed("  10.0.x.0 "), Err(ParseError::UnexpectedCharacter('x', 7)));
+    assert_eq!(parse_cidr_trimmed("   "), parse_cidr(""));
+}
@@ -106,3 +106,593 @@ fn should_not_parse_ipv6() {
         assert_eq!(error, *expected_error);
     }
 }
+
+#[test]
+fn should_match_v6_family_bounds() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 0).expect("to create");
+    let (first, last) = Cidr::family_bounds(ip_cidr::AddrFamily::V6);
+    assert_eq!(first, net::IpAddr::V6(ip_cidr::v6::FIRST));
+    assert_eq!(last, net::IpAddr::V6(ip_cidr::v6::LAST));
+    assert_eq!(cidr.network_addr(), first);
+    assert_eq!(cidr.broadcast_addr(), last);
+    assert_eq!(ip_cidr::v6::FIRST, net::Ipv6Addr::UNSPECIFIED);
+    assert_eq!(ip_cidr::v6::LAST, net::Ipv6Addr::new(u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX, u16::MAX));
+}
+
+#[test]
+fn should_parse_ipv6_with_embedded_ipv4() {
+    let inputs = [
+        ("::ffff:127.0.0.1", net::Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0x7f00, 1)),
+        ("::ffff:0.0.0.0", net::Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0, 0)),
+        ("::1.2.3.4", net::Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0x102, 0x304)),
+        ("64:ff9b::255.255.255.255", net::Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0xffff, 0xffff)),
+        ("1:2:3:4:5:6:7.8.9.10", net::Ipv6Addr::new(1, 2, 3, 4, 5, 6, 0x708, 0x90a)),
+    ];
+
+    for (text, expected_ip) in inputs {
+        println!("Parse '{text}'");
+        let (ip, cidr) = match ip_cidr::parse_ip(text) {
+            Ok(result) => result,
+            Err(error) => panic!("Should parse '{text}' but got error={error}"),
+        };
+        assert_eq!(ip, expected_ip);
+        assert!(cidr.is_none());
+
+        let with_cidr = format!("{text}/96");
+        let (ip, cidr) = ip_cidr::parse_ip(&with_cidr).expect("to parse");
+        assert_eq!(ip, expected_ip);
+        assert_eq!(cidr, Some(96));
+    }
+
+    let inputs = [
+        ("::ffff:1.2.3", ParseError::Ipv4InvalidComponentSize(3, 12)),
+        ("::ffff:1.2.3.4.5", ParseError::Ipv4InvalidComponentSize(5, 14)),
+        ("::ffff:1.2.3.4:1", ParseError::InvalidIpv6),
+        ("::ffff:1.2.3.", ParseError::InvalidIpv4),
+        ("::ffff:1.256.3.4", ParseError::InvalidComponent("256")),
+        ("::ffff:1.f.3.4", ParseError::InvalidComponent("f")),
+        ("1:2:3:4:5:6:7:8.9.10.11", ParseError::Ipv6InvalidComponentSize(9)),
+    ];
+
+    for (text, expected_error) in inputs {
+        println!("Parse '{text}'");
+        let error = ip_cidr::parse_ip(text).expect_err("should fail");
+        assert_eq!(error, expected_error);
+    }
+}
+
+#[test]
+fn should_expand_every_zero_abbreviation_shape() {
+    for size in 0..=8u16 {
+        let components: Vec<u16> = (1..=size).map(|idx| idx * 0x1111).collect();
+
+        for zero_start in 0..=components.len() {
+            let (left, right) = components.split_at(zero_start);
+            let left = left.iter().map(|component| format!("{component:x}")).collect::<Vec<_>>().join(":");
+            let right = right.iter().map(|component| format!("{component:x}")).collect::<Vec<_>>().join(":");
+            let text = format!("{left}::{right}");
+            println!("Parse '{text}'");
+
+            if components.len() == 8 {
+                assert_eq!(ip_cidr::parse_ip(&text).expect_err("should fail"), ParseError::InvalidIpv6);
+                continue;
+            }
+
+            let mut expected = [0u16; 8];
+            expected[..zero_start].copy_from_slice(&components[..zero_start]);
+            expected[8 - (components.len() - zero_start)..].copy_from_slice(&components[zero_start..]);
+            let expected = net::Ipv6Addr::from(expected);
+
+            let (ip, prefix) = match ip_cidr::parse_ip(&text) {
+                Ok(result) => result,
+                Err(error) => panic!("Should parse '{text}' but got error={error}"),
+            };
+            assert_eq!(ip, expected);
+            assert_eq!(prefix, None);
+
+            let with_cidr = format!("{text}/{zero_start}");
+            let (ip, prefix) = match ip_cidr::parse_ip(&with_cidr) {
+                Ok(result) => result,
+                Err(error) => panic!("Should parse '{with_cidr}' but got error={error}"),
+            };
+            assert_eq!(ip, expected);
+            assert_eq!(prefix, Some(zero_start as u8));
+
+            //Single trailing separator after abbreviation is never valid
+            if !right.is_empty() {
+                let text = format!("{text}:");
+                println!("Parse '{text}'");
+                assert_eq!(ip_cidr::parse_ip(&text).expect_err("should fail"), ParseError::InvalidIpv6);
+            }
+        }
+    }
+}
+
+#[test]
+fn should_create_v6_block_at_prefix() {
+    let addr = net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6));
+    let cidr = Cidr::at_prefix(addr, 48).expect("to create");
+    assert_eq!(cidr.to_string(), "2001:db8:1::/48");
+    assert!(cidr.contains(addr));
+
+    assert_eq!(Cidr::at_prefix(addr, 128), Cidr::new(addr, 128));
+    assert_eq!(Cidr::at_prefix(addr, 129), None);
+}
+
+#[test]
+fn should_parse_mixed_case_ipv6() {
+    let inputs = [
+        "2001:db8::1/64",
+        "2001:DB8::1/64",
+        "2001:Db8::1/64",
+        "2001:0DB8:0000:0000:0000:0000:0000:0001/64",
+        "2001:0dB8:0:0:0:0:0:1/64",
+    ];
+
+    for text in inputs {
+        println!("Parse '{text}'");
+        let cidr = match ip_cidr::parse_cidr(text) {
+            Ok(Some(cidr)) => cidr,
+            Ok(None) => panic!("'{text}' has overflowing prefix"),
+            Err(error) => panic!("Should parse '{text}' but got error={error}"),
+        };
+        assert_eq!(cidr, Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 64).unwrap());
+        assert_eq!(cidr.to_string(), "2001:db8::1/64");
+    }
+
+    let inputs = [
+        ("FE80::ABCD:EF", "fe80::abcd:ef/128"),
+        ("fE80::aBcD:Ef", "fe80::abcd:ef/128"),
+        ("::FFFF:10.0.0.1", "::ffff:10.0.0.1/128"),
+        ("FFFF:FFFF:FFFF:FFFF:FFFF:FFFF:FFFF:FFFF", "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128"),
+    ];
+
+    for (text, expected) in inputs {
+        println!("Parse '{text}'");
+        let cidr = ip_cidr::parse_cidr(text).expect("to parse").expect("valid prefix");
+        assert_eq!(cidr.to_string(), expected);
+        assert_eq!(cidr, ip_cidr::parse_cidr(&text.to_lowercase()).expect("to parse").expect("valid prefix"));
+    }
+}
+
+#[test]
+fn should_contain_every_v6_address_in_default_route() {
+    for addr in [net::Ipv6Addr::UNSPECIFIED, net::Ipv6Addr::LOCALHOST, net::Ipv6Addr::from_bits(u128::MAX)] {
+        let cidr = Cidr::new_v6(addr, 0).expect("to create");
+        for addr in [0, 1, u128::MAX >> 1, 1 << 127, u128::MAX - 1, u128::MAX] {
+            let addr = net::Ipv6Addr::from_bits(addr);
+            assert!(cidr.contains(addr.into()), "{addr} is not contained in cidr={cidr}");
+        }
+
+        assert!(!cidr.contains(net::Ipv4Addr::UNSPECIFIED.into()));
+        assert!(!cidr.contains(net::Ipv4Addr::BROADCAST.into()));
+    }
+}
+
+#[test]
+fn should_validate_v6_prefix() {
+    assert!(ip_cidr::v6::is_valid_prefix(0));
+    assert!(ip_cidr::v6::is_valid_prefix(128));
+    assert!(!ip_cidr::v6::is_valid_prefix(129));
+    assert!(Cidr::is_valid_prefix(ip_cidr::AddrFamily::V6, 33));
+    assert!(Cidr::is_valid_prefix(ip_cidr::AddrFamily::V6, 128));
+    assert!(!Cidr::is_valid_prefix(ip_cidr::AddrFamily::V6, 129));
+}
+
+#[test]
+fn should_parse_exact_ipv6_cidr() {
+    let cidr = ip_cidr::parse_cidr_exact("2001:db8::/32").expect("to parse");
+    assert_eq!(cidr, Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap());
+    let cidr = ip_cidr::parse_cidr_exact("::1").expect("to parse");
+    assert_eq!(cidr, Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap());
+
+    assert_eq!(ip_cidr::parse_cidr_exact("::/64:").expect_err("should fail"), ParseError::InvalidCidr("64:"));
+    assert_eq!(ip_cidr::parse_cidr_exact("::/129").expect_err("should fail"), ParseError::Ipv6CidrPrefixOverflow(129));
+}
+
+#[test]
+fn should_display_v6_styles() {
+    use ip_cidr::DisplayStyle;
+
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 24).expect("to create");
+    assert_eq!(cidr.display_style(DisplayStyle::Canonical).to_string(), "2001:db8::/24");
+    assert_eq!(cidr.display_style(DisplayStyle::Netmask).to_string(), "2001:db8::/24");
+    assert_eq!(cidr.display_style(DisplayStyle::Wildcard).to_string(), "2001:db8::/24");
+    assert_eq!(cidr.display_style(DisplayStyle::Expanded).to_string(), "2001:0db8:0000:0000:0000:0000:0000:0000/24");
+}
+
+#[test]
+fn should_parse_unspecified_ipv6_with_prefix() {
+    let inputs = [
+        ("::", net::Ipv6Addr::UNSPECIFIED, None),
+        ("::/0", net::Ipv6Addr::UNSPECIFIED, Some(0)),
+        ("::/128", net::Ipv6Addr::UNSPECIFIED, Some(128)),
+        ("::1/128", net::Ipv6Addr::LOCALHOST, Some(128)),
+        ("::1/0", net::Ipv6Addr::LOCALHOST, Some(0)),
+    ];
+
+    for (text, expected_ip, expected_prefix) in inputs {
+        println!("Parse '{text}'");
+        let (ip, prefix) = match ip_cidr::parse_ip(text) {
+            Ok(result) => result,
+            Err(error) => panic!("Should parse '{text}' but got error={error}"),
+        };
+        assert_eq!(ip, expected_ip);
+        assert_eq!(prefix, expected_prefix);
+    }
+
+    let cidr = ip_cidr::parse_cidr("::/0").expect("to parse").expect("valid prefix");
+    assert_eq!(cidr, Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap());
+    assert!(cidr.contains(net::Ipv6Addr::from_bits(u128::MAX).into()));
+    assert!(!cidr.contains(net::Ipv4Addr::UNSPECIFIED.into()));
+
+    //Prefix after lone abbreviation is validated against IPv6 length
+    assert_eq!(ip_cidr::parse_ip("::/129").expect_err("should fail"), ParseError::Ipv6CidrPrefixOverflow(129));
+    assert_eq!(ip_cidr::parse_ip("::/33").expect("to parse"), (net::Ipv6Addr::UNSPECIFIED.into(), Some(33)));
+    assert_eq!(ip_cidr::parse_ip("::/").expect_err("should fail"), ParseError::MissingCidr);
+    assert_eq!(ip_cidr::parse_ip(":/0").expect_err("should fail"), ParseError::InvalidIpv6);
+    assert_eq!(ip_cidr::parse_ip(":::/0").expect_err("should fail"), ParseError::Ipv6MultipleZeroAbbrv);
+}
+
+#[test]
+fn should_check_v6_canonical() {
+    assert!(Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap().is_canonical());
+    assert!(!Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 32).unwrap().is_canonical());
+    assert!(Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap().is_canonical());
+}
+
+#[test]
+fn should_create_v6_from_host_bits() {
+    let addr = net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+    assert_eq!(ip_cidr::v6::Cidr::from_host_bits(addr, 64), ip_cidr::v6::Cidr::new(addr, 64));
+    assert_eq!(ip_cidr::v6::Cidr::from_host_bits(addr, 128), ip_cidr::v6::Cidr::new(addr, 0));
+    assert_eq!(ip_cidr::v6::Cidr::from_host_bits(addr, 129), None);
+}
+
+#[test]
+fn should_split_v6_into_halves() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    let [low, high] = cidr.halves().expect("to split");
+    assert_eq!(low.to_string(), "2001:db8::/33");
+    assert_eq!(high.to_string(), "2001:db8:8000::/33");
+    for child in cidr.halves().unwrap() {
+        assert!(child.aligns_within(&cidr));
+    }
+    assert_eq!(low.network_addr(), cidr.network_addr());
+    assert_eq!(high.broadcast_addr(), cidr.broadcast_addr());
+
+    let [low, high] = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap().halves().expect("to split");
+    assert_eq!(low.to_string(), "::/1");
+    assert_eq!(high.to_string(), "8000::/1");
+
+    assert_eq!(Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap().halves(), None);
+}
+
+#[test]
+fn should_parse_cidr_with_family_hint() {
+    use ip_cidr::AddrFamily;
+
+    let cidr = ip_cidr::parse_cidr_family("2001:db8::/32", AddrFamily::V6).expect("to parse");
+    assert_eq!(cidr.family(), AddrFamily::V6);
+    assert_eq!(cidr.to_string(), "2001:db8::/32");
+    let cidr = ip_cidr::parse_cidr_family("10.0.0.0/8", AddrFamily::V4).expect("to parse");
+    assert_eq!(cidr.family(), AddrFamily::V4);
+
+    let error = ip_cidr::parse_cidr_family("10.0.0.0/8", AddrFamily::V6).expect_err("should fail");
+    assert_eq!(error, ParseError::FamilyMismatch(AddrFamily::V6));
+    assert_eq!(error.to_string(), "Expected IPv6 address");
+    let error = ip_cidr::parse_cidr_family("::ffff:10.0.0.1", AddrFamily::V4).expect_err("should fail");
+    assert_eq!(error, ParseError::FamilyMismatch(AddrFamily::V4));
+    //Invalid input is reported before family
+    assert_eq!(ip_cidr::parse_cidr_family("::/129", AddrFamily::V4).expect_err("should fail"), ParseError::Ipv6CidrPrefixOverflow(129));
+}
+
+#[test]
+fn should_summarize_v6_block() {
+    let summary = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x17), 124).unwrap().summary();
+    assert_eq!(summary.network, net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x10)));
+    assert_eq!(summary.broadcast, net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1f)));
+    //All addresses are usable
+    assert_eq!(summary.first_host, summary.network);
+    assert_eq!(summary.last_host, summary.broadcast);
+    assert_eq!(summary.usable_hosts, 16);
+    assert_eq!(summary.size, 16);
+    assert_eq!(summary.netmask.to_string(), "ffff:ffff:ffff:ffff:ffff:ffff:ffff:fff0");
+    assert_eq!(summary.wildcard.to_string(), "::f");
+}
+
+#[test]
+fn should_iterate_v6_addresses() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 126).unwrap();
+    let addrs = cidr.iter().map(|addr| addr.to_string()).collect::<Vec<_>>();
+    assert_eq!(addrs, ["2001:db8::", "2001:db8::1", "2001:db8::2", "2001:db8::3"]);
+    assert_eq!(cidr.iter().next_back(), Some(net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 3))));
+
+    //Whole address space saturates length but iterates from both ends without overflow
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap();
+    let mut iter = cidr.iter();
+    assert_eq!(iter.len(), usize::MAX);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(Cidr::V6(cidr).iter().size_hint(), (usize::MAX, None));
+    assert_eq!(iter.next_back(), Some(ip_cidr::v6::LAST));
+    assert_eq!(iter.next_back(), Some(net::Ipv6Addr::from_bits(u128::MAX - 1)));
+    assert_eq!(iter.next(), Some(ip_cidr::v6::FIRST));
+    assert_eq!(iter.next(), Some(net::Ipv6Addr::from_bits(1)));
+
+    let cidr = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 120).unwrap();
+    assert_eq!(cidr.iter().size_hint(), (256, Some(256)));
+
+    let cidr = ip_cidr::v6::Cidr::new_single(ip_cidr::v6::LAST);
+    let mut iter = cidr.iter();
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(ip_cidr::v6::LAST));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn should_iterate_v6_hosts() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 127).unwrap();
+    let hosts = cidr.hosts();
+    assert_eq!(hosts.len(), 2);
+    assert_eq!(hosts.collect::<Vec<_>>(), cidr.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn should_split_v6_into_subnets() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    let subnets = cidr.subnets(34).expect("to split").map(|subnet| subnet.to_string()).collect::<Vec<_>>();
+    assert_eq!(subnets, ["2001:db8::/34", "2001:db8:4000::/34", "2001:db8:8000::/34", "2001:db8:c000::/34"]);
+    assert!(cidr.subnets(31).is_none());
+    assert!(cidr.subnets(129).is_none());
+
+    //Number of subnets does not fit `usize`, hence no upper bound
+    let huge = cidr.subnets(128).expect("to split");
+    assert_eq!(huge.len(), usize::MAX);
+    assert_eq!(huge.size_hint(), (usize::MAX, None));
+    assert_eq!(cidr.subnets(64).expect("to split").size_hint(), (1 << 32, Some(1 << 32)));
+
+    let all = ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap();
+    let mut subnets = all.subnets(128).expect("to split");
+    assert_eq!(subnets.len(), usize::MAX);
+    assert_eq!(subnets.size_hint(), (usize::MAX, None));
+    assert_eq!(subnets.next_back(), Some(ip_cidr::v6::Cidr::new_single(ip_cidr::v6::LAST)));
+    assert_eq!(subnets.next(), Some(ip_cidr::v6::Cidr::new_single(ip_cidr::v6::FIRST)));
+    assert_eq!(all.subnets(0).expect("to split").collect::<Vec<_>>(), [all]);
+}
+
+#[test]
+fn should_widen_v6_to_supernet() {
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0x8000, 0, 0, 0, 0, 1), 33).unwrap();
+    assert_eq!(cidr.supernet().expect("to widen").to_string(), "2001:db8::/32");
+    assert_eq!(cidr.supernet_at(16).expect("to widen").to_string(), "2001::/16");
+    assert_eq!(cidr.supernet_at(34), None);
+    assert_eq!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 0).unwrap().supernet(), None);
+}
+
+#[test]
+fn should_return_v6_netmask() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    assert_eq!(cidr.netmask().to_string(), "ffff:ffff::");
+    assert_eq!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 0).unwrap().netmask(), net::Ipv6Addr::UNSPECIFIED);
+    assert_eq!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 128).unwrap().netmask(), ip_cidr::v6::LAST);
+}
+
+#[test]
+fn should_return_v6_wildcard_mask() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 112).unwrap();
+    assert_eq!(cidr.wildcard_mask().to_string(), "::ffff");
+    assert_eq!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap().wildcard_mask(), ip_cidr::v6::LAST);
+    assert_eq!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 128).unwrap().wildcard_mask(), net::Ipv6Addr::UNSPECIFIED);
+}
+
+#[test]
+fn should_classify_v6_network_addr() {
+    assert!(ip_cidr::parse_cidr_exact("fc00::/7").unwrap().is_private());
+    assert!(ip_cidr::parse_cidr_exact("fd12:3456::/32").unwrap().is_private());
+    assert!(!ip_cidr::parse_cidr_exact("fe80::/10").unwrap().is_private());
+    assert!(!ip_cidr::parse_cidr_exact("::ffff:10.0.0.0/104").unwrap().is_private());
+
+    assert!(ip_cidr::parse_cidr_exact("::1").unwrap().is_loopback());
+    assert!(!ip_cidr::parse_cidr_exact("::1/127").unwrap().is_loopback());
+    assert!(ip_cidr::parse_cidr_exact("ff02::1/16").unwrap().is_multicast());
+    assert!(!ip_cidr::parse_cidr_exact("fe80::/10").unwrap().is_multicast());
+    assert!(ip_cidr::parse_cidr_exact("::/0").unwrap().is_unspecified());
+    assert!(!ip_cidr::parse_cidr_exact("::1").unwrap().is_unspecified());
+}
+
+#[test]
+fn should_detect_v6_block_straddling_reserved() {
+    assert!(ip_cidr::cidr!("::/0").straddles_reserved());
+    assert!(ip_cidr::cidr!("2001::/16").straddles_reserved());
+    assert!(ip_cidr::cidr!("fc00::/6").straddles_reserved());
+
+    assert!(!ip_cidr::cidr!("fd00::/8").straddles_reserved());
+    assert!(!ip_cidr::cidr!("2001:db8:1::/48").straddles_reserved());
+    assert!(!ip_cidr::cidr!("2a00::/16").straddles_reserved());
+}
+
+#[test]
+fn should_return_v6_range() {
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 7), 126).unwrap();
+    assert_eq!(cidr.range(), (net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 4), net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 7)));
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::LOCALHOST, 0).unwrap();
+    assert_eq!(cidr.range(), (ip_cidr::v6::FIRST, ip_cidr::v6::LAST));
+}
+
+#[test]
+fn should_parse_ipv6_zone() {
+    let link_local = net::IpAddr::V6(net::Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+    assert_eq!(ip_cidr::parse_ip_zoned("fe80::1%eth0").expect("to parse"), (link_local, Some("eth0"), None));
+    assert_eq!(ip_cidr::parse_ip_zoned("fe80::1%3").expect("to parse"), (link_local, Some("3"), None));
+    assert_eq!(ip_cidr::parse_ip_zoned("fe80::1%eth0/64").expect("to parse"), (link_local, Some("eth0"), Some(64)));
+    assert_eq!(ip_cidr::parse_ip_zoned("fe80::1/64").expect("to parse"), (link_local, None, Some(64)));
+    assert_eq!(ip_cidr::parse_ip_zoned("::ffff:10.0.0.1%en0").expect("to parse").1, Some("en0"));
+    assert_eq!(ip_cidr::parse_ip_zoned("10.0.0.1").expect("to parse"), (net::Ipv4Addr::new(10, 0, 0, 1).into(), None, None));
+
+    let inputs = [
+        ("fe80::1%", ParseError::InvalidZone),
+        ("fe80::1%/64", ParseError::InvalidZone),
+        ("fe80::1% ", ParseError::InvalidZone),
+        ("fe80::1%eth0%1", ParseError::UnexpectedCharacter('%', 12)),
+        ("fe80::1%eth 0", ParseError::UnexpectedCharacter(' ', 11)),
+        ("fe80::1%eth0\n", ParseError::TrailingWhitespace(12)),
+        ("fe80::1%eth0/129", ParseError::Ipv6CidrPrefixOverflow(129)),
+        ("fe80::1/64%eth0", ParseError::InvalidCidr("64%eth0")),
+        ("fe80:%eth0", ParseError::InvalidIpv6),
+        //Zone is only allowed for IPv6
+        ("10.0.0.1%eth0", ParseError::UnexpectedCharacter('%', 8)),
+        ("%eth0", ParseError::UnexpectedCharacter('%', 0)),
+    ];
+    for (text, expected_error) in inputs {
+        println!("Parse '{}'", text.escape_debug());
+        assert_eq!(ip_cidr::parse_ip_zoned(text).expect_err("should fail"), expected_error);
+    }
+
+    //Zone is still rejected by default
+    assert_eq!(ip_cidr::parse_ip("fe80::1%eth0").expect_err("should fail"), ParseError::UnexpectedCharacter('%', 7));
+    assert_eq!(ip_cidr::parse_ip_zoned("fe80::1%").expect_err("should fail").to_string(), "IPv6 zone is empty");
+}
+
+#[test]
+fn should_find_v6_index_of_addr() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64).unwrap();
+    assert_eq!(cidr.index_of(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 1, 2).into()), Some(0x1_0002));
+    assert_eq!(cidr.index_of(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 0).into()), None);
+    assert_eq!(cidr.index_of(net::Ipv4Addr::UNSPECIFIED.into()), None);
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(cidr.index_of(ip_cidr::v6::LAST), Some(u128::MAX));
+}
+
+#[test]
+fn should_display_v6_network_in_alternate_form() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 5), 32).unwrap();
+    assert_eq!(cidr.to_string(), "2001:db8::5/32");
+    assert_eq!(format!("{cidr:#}"), "2001:db8::/32");
+    let Cidr::V6(typed) = cidr else {
+        unreachable!();
+    };
+    assert_eq!(format!("{typed:#}"), "2001:db8::/32");
+
+    let other = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0xffff, 0, 0, 0, 0, 1), 32).unwrap();
+    assert_ne!(cidr, other);
+    assert_eq!(format!("{cidr:#}"), format!("{other:#}"));
+    assert_eq!(format!("{:#}", Cidr::new_v4(net::Ipv4Addr::new(10, 1, 2, 3), 8).unwrap()), "10.0.0.0/8");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn should_convert_v6_to_canonical_string() {
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 5), 32).unwrap();
+    assert_eq!(cidr.to_canonical_string(), "2001:db8::/32");
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 5), 80).unwrap();
+    assert_eq!(cidr.to_canonical_string(), "2001:db8:0:0:1::/80");
+    assert_eq!(Cidr::V6(cidr).to_canonical_string(), "2001:db8:0:0:1::/80");
+    assert_eq!(Cidr::new_v4(net::Ipv4Addr::new(10, 1, 2, 3), 8).unwrap().to_canonical_string(), "10.0.0.0/8");
+}
+
+#[test]
+fn should_step_to_adjacent_v6_blocks() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    assert_eq!(cidr.next().unwrap().to_string(), "2001:db9::/32");
+    assert_eq!(cidr.prev().unwrap().to_string(), "2001:db7::/32");
+    assert_eq!(Cidr::new_v6(ip_cidr::v6::LAST, 1).unwrap().next(), None);
+    assert_eq!(Cidr::new_v6(ip_cidr::v6::LAST, 1).unwrap().prev().unwrap().to_string(), "::/1");
+    assert_eq!(Cidr::new_v6(ip_cidr::v6::LAST, 0).unwrap().next(), None);
+}
+
+#[test]
+fn should_get_prefix_from_netmask() {
+    use ip_cidr::v6::prefix_from_netmask;
+
+    assert_eq!(prefix_from_netmask(net::Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0xffff, 0, 0, 0, 0)), Some(64));
+    assert_eq!(prefix_from_netmask(net::Ipv6Addr::new(0xffff, 0xfe00, 0, 0, 0, 0, 0, 0)), Some(23));
+    assert_eq!(prefix_from_netmask(net::Ipv6Addr::UNSPECIFIED), Some(0));
+    assert_eq!(prefix_from_netmask(net::Ipv6Addr::from_bits(u128::MAX)), Some(128));
+    assert_eq!(prefix_from_netmask(net::Ipv6Addr::new(0xffff, 0, 0xffff, 0, 0, 0, 0, 0)), None);
+}
+
+#[test]
+fn should_format_ipv6_as_hex_and_binary() {
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    assert_eq!(format!("{cidr:x}"), "20010db8000000000000000000000000/32");
+    assert_eq!(format!("{cidr:#x}"), "0x20010db8000000000000000000000000/32");
+    let binary = format!("{cidr:b}");
+    assert_eq!(binary.len(), 128 + 3);
+    assert!(binary.starts_with("00100000000000010000110110111000"));
+    assert!(binary.ends_with("0/32"));
+}
+
+#[test]
+fn should_count_ipv6_hosts() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 120).unwrap();
+    assert_eq!(cidr.host_count(), 256);
+    assert_eq!(cidr.host_count(), cidr.size());
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap();
+    assert_eq!(cidr.host_count(), 1);
+}
+
+#[test]
+fn should_convert_ipv6_with_try_from() {
+    let addr = net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+    let cidr = ip_cidr::v6::Cidr::try_from((addr, 32)).expect("to convert");
+    assert_eq!(cidr, ip_cidr::v6::Cidr::new(addr, 32).unwrap());
+    let error = ip_cidr::v6::Cidr::try_from((addr, 129)).unwrap_err();
+    assert_eq!(error, ip_cidr::PrefixError { prefix: 129, max: 128 });
+
+    let cidr = Cidr::try_from((net::IpAddr::V6(addr), 32)).expect("to convert");
+    assert_eq!(Cidr::try_from("2001:db8::/32"), Ok(cidr));
+    assert_eq!(Cidr::try_from("2001:db8::/129"), Err(ParseError::Ipv6CidrPrefixOverflow(129)));
+    assert_eq!(Cidr::try_from((net::IpAddr::V6(addr), 129)).unwrap_err().to_string(), "Prefix '129' is greater than 128");
+}
+
+#[test]
+fn should_not_map_ipv6_outside_of_ipv4_range() {
+    let mapped = net::Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0x0a00, 0);
+    assert!(ip_cidr::v6::Cidr::new(mapped, 95).unwrap().to_ipv4().is_none());
+    assert!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0x0a00, 0), 104).unwrap().to_ipv4().is_none());
+    assert!(ip_cidr::v6::Cidr::new(net::Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0x0a00, 0), 104).unwrap().to_ipv4().is_none());
+}
+
+#[test]
+fn should_construct_ipv6_network() {
+    let cidr = Cidr::new_network_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 9), 64).unwrap();
+    assert_eq!(cidr.addr(), net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)));
+    assert_eq!(cidr, Cidr::new_network_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0, 0, 1), 64).unwrap());
+    assert!(Cidr::new_network_v6(net::Ipv6Addr::LOCALHOST, 129).is_none());
+}
+
+#[test]
+fn should_compute_ipv6_address_distance() {
+    use ip_cidr::address_distance;
+
+    let max = net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX));
+    let min = net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED);
+    assert_eq!(address_distance(min, max), Some(u128::MAX));
+    assert_eq!(address_distance(max, min), Some(u128::MAX));
+    assert_eq!(address_distance(max, max), Some(0));
+    let near_max = net::IpAddr::V6(net::Ipv6Addr::from_bits(u128::MAX - 5));
+    assert_eq!(address_distance(max, near_max), Some(5));
+    assert_eq!(address_distance(near_max, max), Some(5));
+    assert_eq!(address_distance(min, net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)), Some(1));
+    assert_eq!(address_distance(min, net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED)), None);
+}
+
+#[test]
+fn should_split_v6_into_bounded_subnets() {
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    for (new_prefix, max) in [(64, 100), (34, 100), (34, 4), (34, 2), (32, 0), (128, 1)] {
+        let total = cidr.subnets(new_prefix).expect("to split").len();
+        let subnets = cidr.subnets_bounded(new_prefix, max).expect("to split").collect::<Vec<_>>();
+        assert_eq!(subnets.len(), total.min(max));
+        assert!(subnets.iter().copied().eq(cidr.subnets(new_prefix).unwrap().take(max)));
+    }
+
+    let mut subnets = cidr.subnets_bounded(64, 2).expect("to split");
+    assert_eq!(subnets.next(), Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64));
+    assert_eq!(subnets.next(), Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 0), 64));
+    assert_eq!(subnets.next(), None);
+    assert!(cidr.subnets_bounded(31, 10).is_none());
+    assert!(cidr.subnets_bounded(129, 10).is_none());
+}
@@ -0,0 +1,54 @@
+use core::net;
+
+use ip_cidr::{cidr, Cidr};
+
+const TRUSTED: Cidr = cidr!("10.0.0.0/8");
+const LOOPBACK: Cidr = cidr!("::1");
+
+#[test]
+fn should_parse_cidr_literal() {
+    assert_eq!(TRUSTED, Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 8).unwrap());
+    assert_eq!(LOOPBACK, Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap());
+    assert_eq!(cidr!("2001:db8::/32").to_string(), "2001:db8::/32");
+}
+
+const ALLOW_LIST: [Cidr; 3] = [cidr!("10.0.0.0/8"), cidr!("192.168.0.0/16"), cidr!("fc00::/7")];
+const IS_ALLOWED: bool = ip_cidr::contains_any_const(&ALLOW_LIST, net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 1)));
+const IS_DENIED: bool = ip_cidr::contains_any_const(&ALLOW_LIST, net::IpAddr::V4(net::Ipv4Addr::new(172, 16, 0, 1)));
+
+#[test]
+fn should_check_allow_list_at_compile_time() {
+    const {
+        assert!(IS_ALLOWED);
+        assert!(!IS_DENIED);
+    }
+
+    assert!(ip_cidr::contains_any_const(&ALLOW_LIST, net::IpAddr::V6(net::Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1))));
+    assert!(!ip_cidr::contains_any_const(&ALLOW_LIST, net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)));
+    //Family mismatch never matches
+    assert!(!ip_cidr::contains_any_const(&ALLOW_LIST, net::IpAddr::V6(net::Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped())));
+    assert!(!ip_cidr::contains_any_const(&[], net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1))));
+}
+
+#[test]
+fn should_detect_host_block() {
+    const { assert!(cidr!("10.0.0.1").is_host()) };
+    assert!(cidr!("10.0.0.1/32").is_host());
+    assert!(!cidr!("10.0.0.0/31").is_host());
+    assert!(cidr!("::1").is_host());
+    assert!(!cidr!("::1/127").is_host());
+    assert!(!cidr!("::/0").is_host());
+}
+
+#[test]
+fn should_detect_default_route() {
+    const { assert!(Cidr::default_v4().is_default()) };
+    assert_eq!(Cidr::default_v4(), cidr!("0.0.0.0/0"));
+    assert_eq!(Cidr::default_v6(), cidr!("::/0"));
+    assert!(Cidr::default_v6().is_default());
+    assert!(cidr!("10.0.0.1/0").is_default());
+    assert!(!cidr!("0.0.0.0/1").is_default());
+    assert!(!cidr!("::").is_default());
+    assert_eq!(ip_cidr::v4::Cidr::DEFAULT.size(), u32::MAX);
+    assert!(ip_cidr::v6::Cidr::DEFAULT.is_default());
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+
+use core::net;
+
+use ip_cidr::{cidr, CidrMap};
+
+#[test]
+fn should_shadow_country_by_city() {
+    let mut geo = CidrMap::new();
+    assert!(geo.is_empty());
+    assert_eq!(geo.lookup(net::IpAddr::from([81, 2, 3, 4])), None);
+
+    assert_eq!(geo.insert(cidr!("81.0.0.0/8"), "country"), None);
+    assert_eq!(geo.insert(cidr!("81.2.3.77/24"), "city"), None);
+    assert_eq!(geo.insert(cidr!("2001:db8::/32"), "v6 country"), None);
+    assert_eq!(geo.len(), 3);
+
+    assert_eq!(geo.lookup(net::IpAddr::from([81, 2, 3, 4])), Some(&"city"));
+    assert_eq!(geo.longest_match(net::IpAddr::from([81, 2, 3, 4])), Some((cidr!("81.2.3.0/24"), &"city")));
+    assert_eq!(geo.lookup(net::IpAddr::from([81, 2, 4, 4])), Some(&"country"));
+    assert_eq!(geo.longest_match(net::IpAddr::from([81, 2, 4, 4])), Some((cidr!("81.0.0.0/8"), &"country")));
+    assert_eq!(geo.lookup(net::IpAddr::from([82, 0, 0, 0])), None);
+    assert_eq!(geo.lookup(net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))), Some(&"v6 country"));
+    assert_eq!(geo.lookup(net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)), None);
+
+    assert_eq!(geo.insert(cidr!("81.2.3.0/24"), "district"), Some("city"));
+    assert_eq!(geo.len(), 3);
+    assert_eq!(geo.lookup(net::IpAddr::from([81, 2, 3, 4])), Some(&"district"));
+}
+
+#[test]
+fn should_match_linear_scan() {
+    let entries = [(cidr!("0.0.0.0/0"), 0), (cidr!("10.0.0.0/8"), 8), (cidr!("10.128.0.0/9"), 9), (cidr!("10.130.4.0/22"), 22), (cidr!("10.131.255.255/32"), 32)];
+    let map = entries.iter().copied().collect::<CidrMap<u8>>();
+    for third in 0..=255 {
+        for fourth in [0, 1, 127, 255] {
+            for addr in [net::IpAddr::from([10, 130, third, fourth]), net::IpAddr::from([10, 131, third, fourth]), net::IpAddr::from([11, 131, third, fourth])] {
+                let expected = entries.iter().filter(|(block, _)| block.contains(addr)).max_by_key(|(block, _)| block.prefix()).map(|(_, value)| value);
+                assert_eq!(map.lookup(addr), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn should_get_exact_value() {
+    let routes = [(cidr!("10.0.0.0/16"), "core"), (cidr!("10.0.0.0/26"), "edge")].into_iter().collect::<CidrMap<_>>();
+    assert_eq!(routes.get_exact(&cidr!("10.0.0.0/24")), None);
+    assert_eq!(routes.lookup(net::IpAddr::from([10, 0, 0, 200])), Some(&"core"));
+    assert_eq!(routes.get_exact(&cidr!("10.0.0.0/16")), Some(&"core"));
+    assert_eq!(routes.get_exact(&cidr!("10.0.0.1/26")), Some(&"edge"));
+    assert_eq!(routes.get_exact(&cidr!("10.0.0.0/8")), None);
+    assert_eq!(routes.get_exact(&cidr!("::/0")), None);
+}
@@ -0,0 +1,72 @@
+use ip_cidr::{cidr, Cidr};
+
+#[test]
+fn should_sort_by_network_with_key() {
+    let blocks = [
+        cidr!("8000::/1"),
+        cidr!("10.0.1.0/24"),
+        cidr!("10.0.0.0/8"),
+        cidr!("::/0"),
+        cidr!("10.0.0.0/16"),
+        cidr!("192.168.0.0/16"),
+        cidr!("0.0.0.0/0"),
+        cidr!("::1/128"),
+        cidr!("10.0.0.0/24"),
+        cidr!("255.255.255.255/32"),
+    ];
+
+    let mut by_key = blocks.to_vec();
+    by_key.sort_by_key(Cidr::sort_key);
+    assert_eq!(by_key, [
+        cidr!("0.0.0.0/0"),
+        cidr!("10.0.0.0/8"),
+        cidr!("10.0.0.0/16"),
+        cidr!("10.0.0.0/24"),
+        cidr!("10.0.1.0/24"),
+        cidr!("192.168.0.0/16"),
+        cidr!("255.255.255.255/32"),
+        cidr!("::/0"),
+        cidr!("::1/128"),
+        cidr!("8000::/1"),
+    ]);
+
+    //Ord compares prefix before address within the family
+    let mut by_ord = blocks.to_vec();
+    by_ord.sort();
+    assert_eq!(by_ord, [
+        cidr!("0.0.0.0/0"),
+        cidr!("10.0.0.0/8"),
+        cidr!("10.0.0.0/16"),
+        cidr!("192.168.0.0/16"),
+        cidr!("10.0.0.0/24"),
+        cidr!("10.0.1.0/24"),
+        cidr!("255.255.255.255/32"),
+        cidr!("::/0"),
+        cidr!("8000::/1"),
+        cidr!("::1/128"),
+    ]);
+
+    assert_eq!(cidr!("10.0.1.0/24").sort_key(), (4, 0x0a000100, 24));
+    assert_eq!(cidr!("::1/128").sort_key(), (6, 1, 128));
+}
+
+#[test]
+fn should_ignore_host_bits_in_key() {
+    //Host bits do not affect key, but still order otherwise equal blocks
+    let low = cidr!("10.0.0.1/24");
+    let high = cidr!("10.0.0.9/24");
+    assert_eq!(low.sort_key(), high.sort_key());
+    assert!(low < high);
+
+    //Network address takes priority over stored address
+    let wide = cidr!("10.0.0.200/16");
+    let narrow = cidr!("10.0.0.0/24");
+    assert!(wide.sort_key() < narrow.sort_key());
+    assert!(cidr!("10.0.0.0/24").sort_key() < cidr!("10.1.0.0/16").sort_key());
+    assert!(cidr!("10.1.0.0/16") < cidr!("10.0.0.0/24"));
+
+    let mut blocks = [high, narrow, cidr!("10.0.0.0/25"), wide, low];
+    blocks.sort_by_key(Cidr::sort_key);
+    assert!(blocks.is_sorted_by_key(Cidr::sort_key));
+    assert_eq!(blocks[0], wide);
+}
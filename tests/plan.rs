@@ -0,0 +1,53 @@
+#![cfg(feature = "alloc")]
+
+use core::net;
+
+use ip_cidr::{plan_subnets, Cidr};
+
+#[test]
+fn should_plan_v4_subnets() {
+    let block = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 24).expect("to create");
+    let subnets = plan_subnets(&block, &[10, 50, 20]).expect("to fit");
+    assert_eq!(subnets, [
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 96), 28).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 26).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 64), 27).unwrap(),
+    ]);
+
+    for (idx, subnet) in subnets.iter().enumerate() {
+        assert!(block.contains(subnet.network_addr()));
+        assert!(block.contains(subnet.broadcast_addr()));
+        for other in subnets.iter().skip(idx + 1) {
+            assert!(!subnet.contains(other.network_addr()), "{subnet} overlaps {other}");
+            assert!(!other.contains(subnet.network_addr()), "{subnet} overlaps {other}");
+        }
+    }
+
+    //Point-to-point links
+    let subnets = plan_subnets(&block, &[2, 1, 0]).expect("to fit");
+    assert_eq!(subnets, [
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 31).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 2), 32).unwrap(),
+        Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 3), 32).unwrap(),
+    ]);
+
+    assert_eq!(plan_subnets(&block, &[]), Some(Vec::new()));
+    assert_eq!(plan_subnets(&block, &[254]), Some(vec![block]));
+    assert_eq!(plan_subnets(&block, &[255]), None);
+    assert_eq!(plan_subnets(&block, &[126, 126, 1]), None);
+}
+
+#[test]
+fn should_plan_v6_subnets() {
+    let block = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).expect("to create");
+    let subnets = plan_subnets(&block, &[u32::MAX, 2, 1]).expect("to fit");
+    assert_eq!(subnets, [
+        Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 96).unwrap(),
+        Cidr::new_v6(net::Ipv6Addr::from_bits(1 << 32), 127).unwrap(),
+        Cidr::new_v6(net::Ipv6Addr::from_bits((1 << 32) + 2), 128).unwrap(),
+    ]);
+
+    let block = Cidr::new_v6(net::Ipv6Addr::from_bits(u128::MAX), 127).expect("to create");
+    assert_eq!(plan_subnets(&block, &[1, 1]).expect("to fit").len(), 2);
+    assert_eq!(plan_subnets(&block, &[1, 1, 1]), None);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "rand")]
+
+use core::net;
+
+use ip_cidr::Cidr;
+use rand::distr::Distribution;
+use rand::RngCore;
+
+//xorshift64* to keep generated addresses reproducible
+struct Rng(u64);
+
+impl RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[test]
+fn should_sample_addresses_within_block() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(10, 0, 0, 0), 30).unwrap();
+    let mut seen = [false; 4];
+    for _ in 0..64 {
+        let addr = cidr.sample(&mut rng);
+        let idx = cidr.index_of(addr).expect("to be within block");
+        seen[idx as usize] = true;
+    }
+    //Network and broadcast addresses are included
+    assert_eq!(seen, [true; 4]);
+
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    for addr in cidr.sample_iter(&mut rng).take(64) {
+        assert!(cidr.contains(addr), "{addr} is not within {cidr}");
+    }
+
+    //Whole address space uses all bits
+    let cidr = ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap();
+    assert!((0..64).map(|_| cidr.sample(&mut rng)).any(|addr| addr.to_bits() > u64::MAX as u128));
+    let cidr = ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::LOCALHOST);
+    assert_eq!(cidr.sample(&mut rng), net::Ipv4Addr::LOCALHOST);
+}
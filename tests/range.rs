@@ -0,0 +1,142 @@
+#![cfg(feature = "alloc")]
+
+use core::net;
+
+use ip_cidr::{cidr, cidrs_for_count, summarize_range, Cidr, RangeError};
+
+#[test]
+fn should_cover_v4_address_count() {
+    let start = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 0));
+    let blocks = cidrs_for_count(start, 300).expect("to cover");
+    assert_eq!(blocks, [
+        cidr!("10.0.0.0/24"),
+        cidr!("10.0.1.0/27"),
+        cidr!("10.0.1.32/29"),
+        cidr!("10.0.1.40/30"),
+    ]);
+    assert_eq!(blocks.iter().map(Cidr::size).sum::<u128>(), 300);
+
+    let start = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 3));
+    assert_eq!(cidrs_for_count(start, 6).expect("to cover"), [
+        cidr!("10.0.0.3/32"),
+        cidr!("10.0.0.4/30"),
+        cidr!("10.0.0.8/32"),
+    ]);
+
+    let start = net::IpAddr::V4(net::Ipv4Addr::BROADCAST);
+    assert_eq!(cidrs_for_count(start, 1).expect("to cover"), [cidr!("255.255.255.255/32")]);
+    assert_eq!(cidrs_for_count(start, 2), Err(RangeError::Overflow));
+    assert_eq!(cidrs_for_count(start, 0), Err(RangeError::Empty));
+
+    let start = net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED);
+    assert_eq!(cidrs_for_count(start, 1 << 32).expect("to cover"), [cidr!("0.0.0.0/0")]);
+    assert_eq!(cidrs_for_count(start, (1 << 32) + 1), Err(RangeError::Overflow));
+}
+
+#[test]
+fn should_cover_v6_address_count() {
+    let start = net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED);
+    let blocks = cidrs_for_count(start, u128::MAX).expect("to cover");
+    assert_eq!(blocks.len(), 128);
+    for (idx, block) in blocks.iter().enumerate() {
+        assert_eq!(block.prefix() as usize, idx + 1);
+    }
+    assert_eq!(blocks.last().unwrap().network_addr(), net::Ipv6Addr::from_bits(u128::MAX - 1));
+
+    let start = net::IpAddr::V6(net::Ipv6Addr::from_bits(1));
+    let blocks = cidrs_for_count(start, u128::MAX).expect("to cover");
+    assert_eq!(blocks.len(), 128);
+    assert_eq!(blocks.last().unwrap().broadcast_addr(), net::Ipv6Addr::from_bits(u128::MAX));
+    assert_eq!(cidrs_for_count(start, u128::MAX - 1).expect("to cover").len(), 254);
+
+    let start = net::IpAddr::V6(net::Ipv6Addr::from_bits(2));
+    assert_eq!(cidrs_for_count(start, u128::MAX), Err(RangeError::Overflow));
+}
+
+#[test]
+fn should_compute_symmetric_difference() {
+    let parent = cidr!("10.0.0.0/24");
+    let child = cidr!("10.0.0.64/26");
+    let expected = [cidr!("10.0.0.0/26"), cidr!("10.0.0.128/25")];
+    assert_eq!(parent.symmetric_difference(&child), expected);
+    assert_eq!(child.symmetric_difference(&parent), expected);
+
+    let child = cidr!("10.0.0.5/32");
+    assert_eq!(parent.symmetric_difference(&child), [
+        cidr!("10.0.0.0/30"),
+        cidr!("10.0.0.4/32"),
+        cidr!("10.0.0.6/31"),
+        cidr!("10.0.0.8/29"),
+        cidr!("10.0.0.16/28"),
+        cidr!("10.0.0.32/27"),
+        cidr!("10.0.0.64/26"),
+        cidr!("10.0.0.128/25"),
+    ]);
+
+    //Disjoint blocks are returned as is, with host bits cleared
+    let other = cidr!("10.0.1.7/24");
+    assert_eq!(other.symmetric_difference(&parent), [parent, cidr!("10.0.1.0/24")]);
+
+    //Different families are always disjoint
+    let v6 = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).expect("to create");
+    assert_eq!(v6.symmetric_difference(&parent), [parent, v6]);
+
+    assert_eq!(parent.symmetric_difference(&parent), []);
+    assert_eq!(parent.symmetric_difference(&cidr!("10.0.0.1/24")), []);
+
+    let all = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).expect("to create");
+    let last = Cidr::new_v6(net::Ipv6Addr::from_bits(u128::MAX), 128).expect("to create");
+    let difference = all.symmetric_difference(&last);
+    assert_eq!(difference.len(), 128);
+    assert_eq!(difference[0].to_string(), "::/1");
+    assert_eq!(difference.iter().map(Cidr::size).sum::<u128>(), u128::MAX);
+}
+
+#[test]
+fn should_summarize_range() {
+    let start = net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 1));
+    let end = net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 254));
+    assert_eq!(summarize_range(start, end).expect("to summarize"), [
+        cidr!("192.168.1.1/32"),
+        cidr!("192.168.1.2/31"),
+        cidr!("192.168.1.4/30"),
+        cidr!("192.168.1.8/29"),
+        cidr!("192.168.1.16/28"),
+        cidr!("192.168.1.32/27"),
+        cidr!("192.168.1.64/26"),
+        cidr!("192.168.1.128/26"),
+        cidr!("192.168.1.192/27"),
+        cidr!("192.168.1.224/28"),
+        cidr!("192.168.1.240/29"),
+        cidr!("192.168.1.248/30"),
+        cidr!("192.168.1.252/31"),
+        cidr!("192.168.1.254/32"),
+    ]);
+
+    assert_eq!(summarize_range(start, start).expect("to summarize"), [cidr!("192.168.1.1/32")]);
+    let all = (net::IpAddr::V4(net::Ipv4Addr::UNSPECIFIED), net::IpAddr::V4(net::Ipv4Addr::BROADCAST));
+    assert_eq!(summarize_range(all.0, all.1).expect("to summarize"), [cidr!("0.0.0.0/0")]);
+    let all = (net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED), net::IpAddr::V6(ip_cidr::v6::LAST));
+    assert_eq!(summarize_range(all.0, all.1).expect("to summarize"), [Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap()]);
+
+    assert_eq!(summarize_range(end, start), Err(RangeError::Empty));
+    let error = summarize_range(start, net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)).expect_err("should fail");
+    assert_eq!(error, RangeError::FamilyMismatch);
+    assert_eq!(error.to_string(), "Range bounds are of different address families");
+}
+
+#[test]
+fn should_exclude_sub_block() {
+    let block = cidr!("10.0.0.0/24");
+    assert_eq!(block.exclude(&cidr!("10.0.0.0/25")), [cidr!("10.0.0.128/25")]);
+    assert_eq!(block.exclude(&cidr!("10.0.0.128/25")), [cidr!("10.0.0.0/25")]);
+    assert_eq!(block.exclude(&cidr!("10.0.0.64/26")), [cidr!("10.0.0.0/26"), cidr!("10.0.0.128/25")]);
+    assert_eq!(block.exclude(&cidr!("10.0.0.255/32")).len(), 8);
+
+    assert_eq!(block.exclude(&block), []);
+    assert_eq!(block.exclude(&cidr!("10.0.0.0/8")), []);
+    assert_eq!(block.exclude(&cidr!("10.0.1.0/25")), [block]);
+    let v6 = Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(block.exclude(&v6), [block]);
+    assert_eq!(v6.exclude(&Cidr::new_v6(net::Ipv6Addr::UNSPECIFIED, 1).unwrap()), [Cidr::new_v6(net::Ipv6Addr::from_bits(1 << 127), 1).unwrap()]);
+}
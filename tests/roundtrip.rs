@@ -0,0 +1,84 @@
+use core::net;
+
+use ip_cidr::Cidr;
+
+//xorshift64* to keep generated inputs reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+}
+
+fn assert_round_trip(cidr: Cidr) {
+    let text = cidr.to_string();
+    match ip_cidr::parse_cidr(&text) {
+        Ok(Some(parsed)) => assert_eq!(parsed, cidr, "'{text}' does not round trip"),
+        Ok(None) => panic!("'{text}' has overflowing prefix"),
+        Err(error) => panic!("Should parse '{text}' but got error={error}"),
+    }
+
+    let text = cidr.addr().to_string();
+    match ip_cidr::parse_ip(&text) {
+        Ok((ip, None)) => assert_eq!(ip, cidr.addr(), "'{text}' does not round trip"),
+        Ok((_, Some(prefix))) => panic!("'{text}' should not have prefix but got {prefix}"),
+        Err(error) => panic!("Should parse '{text}' but got error={error}"),
+    }
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn should_round_trip_v4_display() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+    for prefix in 0..=Cidr::IPV4_BITS {
+        let edges = [ip_cidr::v4::FIRST, ip_cidr::v4::LAST, net::Ipv4Addr::LOCALHOST];
+        for addr in edges {
+            assert_round_trip(Cidr::new_v4(addr, prefix).expect("to create"));
+        }
+
+        for _ in 0..64 {
+            let addr = net::Ipv4Addr::from_bits(rng.next_u64() as u32);
+            assert_round_trip(Cidr::new_v4(addr, prefix).expect("to create"));
+        }
+    }
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn should_round_trip_v6_display() {
+    let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+
+    for prefix in 0..=Cidr::IPV6_BITS {
+        let edges = [
+            ip_cidr::v6::FIRST,
+            ip_cidr::v6::LAST,
+            net::Ipv6Addr::LOCALHOST,
+            net::Ipv6Addr::new(1, 0, 0, 0, 0, 0, 0, 0),
+            net::Ipv6Addr::new(1, 0, 1, 0, 0, 1, 0, 1),
+            net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 0),
+        ];
+        for addr in edges {
+            assert_round_trip(Cidr::new_v6(addr, prefix).expect("to create"));
+        }
+
+        for _ in 0..64 {
+            let bits = rng.next_u128();
+            //Sparse addresses exercise zero compression
+            let sparse = bits & rng.next_u128() & rng.next_u128();
+            //IPv4-mapped addresses are displayed with embedded IPv4
+            let mapped = net::Ipv4Addr::from_bits(bits as u32).to_ipv6_mapped();
+            for addr in [net::Ipv6Addr::from_bits(bits), net::Ipv6Addr::from_bits(sparse), mapped] {
+                assert_round_trip(Cidr::new_v6(addr, prefix).expect("to create"));
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "serde")]
+
+use core::net;
+
+use ip_cidr::Cidr;
+
+#[test]
+fn should_round_trip_cidr_through_json() {
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 0, 0), 24).unwrap();
+    let json = serde_json::to_string(&cidr).expect("to serialize");
+    assert_eq!(json, "\"192.168.0.0/24\"");
+    assert_eq!(serde_json::from_str::<Cidr>(&json).expect("to deserialize"), cidr);
+
+    let cidr = Cidr::new_v6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    let json = serde_json::to_string(&cidr).expect("to serialize");
+    assert_eq!(json, "\"2001:db8::/32\"");
+    assert_eq!(serde_json::from_str::<Cidr>(&json).expect("to deserialize"), cidr);
+
+    let cidr = serde_json::from_str::<ip_cidr::v4::Cidr>("\"10.0.0.1\"").expect("to deserialize");
+    assert_eq!(cidr, ip_cidr::v4::Cidr::new_single(net::Ipv4Addr::new(10, 0, 0, 1)));
+    let cidr = serde_json::from_str::<ip_cidr::v6::Cidr>("\"::/0\"").expect("to deserialize");
+    assert_eq!(cidr, ip_cidr::v6::Cidr::new(net::Ipv6Addr::UNSPECIFIED, 0).unwrap());
+}
+
+#[test]
+fn should_report_json_parse_error() {
+    let error = serde_json::from_str::<Cidr>("\"10.0.0.0/33\"").expect_err("should fail");
+    assert_eq!(error.to_string(), "Prefix '33' is greater than 32 at line 1 column 13");
+    let error = serde_json::from_str::<ip_cidr::v6::Cidr>("\"10.0.0.0/8\"").expect_err("should fail");
+    assert_eq!(error.to_string(), "Expected IPv6 address at line 1 column 12");
+    let error = serde_json::from_str::<Cidr>("8").expect_err("should fail");
+    assert_eq!(error.to_string(), "invalid type: integer `8`, expected IP CIDR at line 1 column 1");
+}
+
+#[test]
+fn should_round_trip_cidr_through_binary() {
+    let mut buffer = [0u8; 32];
+
+    let cidr = Cidr::new_v4(net::Ipv4Addr::new(192, 168, 0, 0), 24).unwrap();
+    let bytes = postcard::to_slice(&cidr, &mut buffer).expect("to serialize");
+    //Length followed by octets and prefix
+    assert_eq!(bytes, [5, 192, 168, 0, 0, 24]);
+    assert_eq!(postcard::from_bytes::<Cidr>(bytes).expect("to deserialize"), cidr);
+    assert_eq!(postcard::from_bytes::<ip_cidr::v4::Cidr>(bytes).expect("to deserialize"), cidr_v4(&cidr));
+    postcard::from_bytes::<ip_cidr::v6::Cidr>(bytes).expect_err("should fail");
+
+    let cidr = Cidr::new_v6(net::Ipv6Addr::LOCALHOST, 128).unwrap();
+    let bytes = postcard::to_slice(&cidr, &mut buffer).expect("to serialize");
+    assert_eq!(bytes.len(), 18);
+    assert_eq!(postcard::from_bytes::<Cidr>(bytes).expect("to deserialize"), cidr);
+
+    //Prefix overflow
+    postcard::from_bytes::<Cidr>(&[5, 10, 0, 0, 0, 33]).expect_err("should fail");
+    postcard::from_bytes::<Cidr>(&[4, 10, 0, 0, 0]).expect_err("should fail");
+}
+
+fn cidr_v4(cidr: &Cidr) -> ip_cidr::v4::Cidr {
+    match cidr {
+        Cidr::V4(cidr) => *cidr,
+        Cidr::V6(_) => unreachable!(),
+    }
+}
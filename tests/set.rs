@@ -0,0 +1,413 @@
+#![cfg(feature = "alloc")]
+
+use core::net;
+
+use ip_cidr::{aggregate, cidr, Cidr, CidrSet, CidrSetBuilder, DecodeError, IpRange};
+
+#[test]
+fn should_lookup_v4_blocks() {
+    let mut set = CidrSet::new();
+    assert!(set.is_empty());
+    assert!(!set.contains(net::IpAddr::from([10, 0, 0, 1])));
+
+    assert!(set.insert(cidr!("10.0.0.0/8")));
+    assert!(set.insert(cidr!("10.1.0.0/16")));
+    assert!(set.insert(cidr!("192.168.1.1/24")));
+    assert!(!set.insert(cidr!("192.168.1.0/24")));
+    assert_eq!(set.len(), 3);
+
+    assert!(set.contains(net::IpAddr::from([10, 200, 0, 1])));
+    assert_eq!(set.matching(net::IpAddr::from([10, 200, 0, 1])), Some(cidr!("10.0.0.0/8")));
+    assert_eq!(set.matching(net::IpAddr::from([10, 1, 2, 3])), Some(cidr!("10.1.0.0/16")));
+    assert_eq!(set.matching(net::IpAddr::from([192, 168, 1, 255])), Some(cidr!("192.168.1.0/24")));
+    assert_eq!(set.matching(net::IpAddr::from([192, 168, 2, 0])), None);
+    assert_eq!(set.matching(net::IpAddr::from([11, 0, 0, 0])), None);
+    assert!(!set.contains(net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)));
+}
+
+#[test]
+fn should_lookup_edge_prefixes() {
+    let mut set = [cidr!("0.0.0.0/0"), cidr!("1.2.3.4/32"), cidr!("::1/128")].into_iter().collect::<CidrSet>();
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.matching(net::IpAddr::from([1, 2, 3, 4])), Some(cidr!("1.2.3.4/32")));
+    assert_eq!(set.matching(net::IpAddr::from([1, 2, 3, 5])), Some(cidr!("0.0.0.0/0")));
+    assert_eq!(set.matching(net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)), Some(cidr!("::1/128")));
+    assert_eq!(set.matching(net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED)), None);
+
+    set.extend([cidr!("2001:db8::/32"), cidr!("::/0")]);
+    assert_eq!(set.len(), 5);
+    assert_eq!(set.matching(net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 5))), Some(cidr!("2001:db8::/32")));
+    assert_eq!(set.matching(net::IpAddr::V6(net::Ipv6Addr::UNSPECIFIED)), Some(cidr!("::/0")));
+}
+
+#[test]
+fn should_match_linear_scan() {
+    let blocks = [cidr!("10.0.0.0/8"), cidr!("10.128.0.0/9"), cidr!("10.130.0.0/15"), cidr!("10.130.4.0/22"), cidr!("10.131.255.255/32")];
+    let set = blocks.iter().copied().collect::<CidrSet>();
+    for third in 0..=255 {
+        for fourth in [0, 1, 127, 255] {
+            let addr = net::IpAddr::from([10, 131, third, fourth]);
+            let expected = blocks.iter().filter(|block| block.contains(addr)).max_by_key(|block| block.prefix()).copied();
+            assert_eq!(set.matching(addr), expected);
+            let addr = net::IpAddr::from([10, 130, third, fourth]);
+            let expected = blocks.iter().filter(|block| block.contains(addr)).max_by_key(|block| block.prefix()).copied();
+            assert_eq!(set.matching(addr), expected);
+        }
+    }
+}
+
+#[test]
+fn should_find_longest_match_in_routing_table() {
+    let table = [
+        cidr!("0.0.0.0/0"),
+        cidr!("172.16.0.0/12"),
+        cidr!("172.16.0.0/16"),
+        cidr!("172.16.5.0/24"),
+        cidr!("2001:db8::/32"),
+        cidr!("2001:db8:1::/48"),
+    ].into_iter().collect::<CidrSet>();
+
+    assert_eq!(table.longest_match(net::IpAddr::from([172, 16, 5, 7])), Some(cidr!("172.16.5.0/24")));
+    assert_eq!(table.longest_match(net::IpAddr::from([172, 16, 6, 7])), Some(cidr!("172.16.0.0/16")));
+    assert_eq!(table.longest_match(net::IpAddr::from([172, 17, 0, 1])), Some(cidr!("172.16.0.0/12")));
+    assert_eq!(table.longest_match(net::IpAddr::from([8, 8, 8, 8])), Some(cidr!("0.0.0.0/0")));
+
+    let addr = net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 1));
+    assert_eq!(table.longest_match(addr), Some(cidr!("2001:db8:1::/48")));
+    assert_eq!(table.longest_match(addr), table.matching(addr));
+    let addr = net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 2, 0, 0, 0, 0, 1));
+    assert_eq!(table.longest_match(addr), Some(cidr!("2001:db8::/32")));
+    assert_eq!(table.longest_match(net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)), None);
+}
+
+#[test]
+fn should_iterate_in_ascending_order() {
+    let blocks = [
+        cidr!("2001:db8::/32"),
+        cidr!("192.168.1.0/24"),
+        cidr!("10.1.0.0/16"),
+        cidr!("::/0"),
+        cidr!("10.0.0.0/8"),
+        cidr!("10.0.0.0/16"),
+        cidr!("0.0.0.0/0"),
+        cidr!("255.255.255.255/32"),
+    ];
+    let set = blocks.iter().copied().collect::<CidrSet>();
+    let mut expected = blocks.to_vec();
+    expected.sort_unstable_by_key(ip_cidr::Cidr::sort_key);
+    assert_eq!(set.iter().collect::<Vec<_>>(), expected);
+    assert_eq!((&set).into_iter().count(), set.len());
+    assert_eq!(CidrSet::new().iter().next(), None);
+}
+
+#[test]
+fn should_compute_coverage_fraction() {
+    let pool = cidr!("10.0.0.0/24");
+    assert_eq!(pool.coverage_fraction(&CidrSet::new()), 0.0);
+
+    let half = [cidr!("10.0.0.0/26"), cidr!("10.0.0.128/26"), cidr!("10.0.0.0/27")].into_iter().collect::<CidrSet>();
+    assert_eq!(pool.coverage_fraction(&half), 0.5);
+    let quarter = [cidr!("10.0.0.64/27"), cidr!("10.0.0.96/27"), cidr!("10.0.1.0/24"), cidr!("::/0")].into_iter().collect::<CidrSet>();
+    assert_eq!(pool.coverage_fraction(&quarter), 0.25);
+
+    let full = [cidr!("10.0.0.0/8")].into_iter().collect::<CidrSet>();
+    assert_eq!(pool.coverage_fraction(&full), 1.0);
+    assert_eq!(cidr!("0.0.0.0/0").coverage_fraction(&full), 1.0 / 256.0);
+    let single = [cidr!("::/128")].into_iter().collect::<CidrSet>();
+    assert_eq!(cidr!("::/0").coverage_fraction(&single), 1.0 / 2f64.powi(128));
+}
+
+#[test]
+fn should_count_difference_blocks() {
+    let all = [cidr!("10.0.0.0/24"), cidr!("2001:db8::/32")].into_iter().collect::<CidrSet>();
+    let used = [cidr!("10.0.0.0/26"), cidr!("10.0.0.128/26"), cidr!("2001:db8::/33")].into_iter().collect::<CidrSet>();
+    //10.0.0.64/26, 10.0.0.192/26 and 2001:db8:8000::/33
+    assert_eq!(all.difference_count(&used), 3);
+    assert_eq!(used.difference_count(&all), 0);
+    assert_eq!(all.difference_count(&CidrSet::new()), 2);
+    assert_eq!(CidrSet::new().difference_count(&all), 0);
+
+    //Single address excluded from the middle of /0 leaves one block per remaining prefix length
+    let whole = [cidr!("0.0.0.0/0")].into_iter().collect::<CidrSet>();
+    let hole = [cidr!("10.0.0.1/32")].into_iter().collect::<CidrSet>();
+    assert_eq!(whole.difference_count(&hole), 32);
+
+    //Overlapping and adjacent blocks of subtrahend are handled as single range
+    let pool = [cidr!("10.0.0.0/23")].into_iter().collect::<CidrSet>();
+    let used = [cidr!("10.0.0.0/25"), cidr!("10.0.0.0/26"), cidr!("10.0.0.128/25"), cidr!("10.0.1.0/32")].into_iter().collect::<CidrSet>();
+    assert_eq!(pool.difference_count(&used), 8);
+}
+
+#[test]
+fn should_return_all_matches_by_specificity() {
+    let set = [cidr!("10.1.2.0/24"), cidr!("10.0.0.0/8"), cidr!("10.1.0.0/16"), cidr!("10.1.3.0/24"), cidr!("::/0")].into_iter().collect::<CidrSet>();
+    let matches = set.matches(net::IpAddr::from([10, 1, 2, 3]));
+    assert_eq!(matches.len(), 3);
+    assert_eq!(matches.collect::<Vec<_>>(), [cidr!("10.1.2.0/24"), cidr!("10.1.0.0/16"), cidr!("10.0.0.0/8")]);
+    assert_eq!(set.matches(net::IpAddr::from([10, 2, 0, 0])).collect::<Vec<_>>(), [cidr!("10.0.0.0/8")]);
+    assert_eq!(set.matches(net::IpAddr::from([11, 0, 0, 0])).next(), None);
+    assert_eq!(set.matches(net::IpAddr::V6(net::Ipv6Addr::LOCALHOST)).collect::<Vec<_>>(), [cidr!("::/0")]);
+
+    for addr in [net::IpAddr::from([10, 1, 2, 3]), net::IpAddr::from([10, 1, 3, 255]), net::IpAddr::from([10, 200, 0, 0]), net::IpAddr::from([1, 1, 1, 1])] {
+        assert_eq!(set.matches(addr).next(), set.longest_match(addr));
+    }
+}
+
+#[test]
+fn should_build_normalized_set() {
+    let blocks = [
+        cidr!("10.0.0.0/25"),
+        cidr!("10.0.0.128/25"),
+        cidr!("10.0.0.64/26"),
+        cidr!("192.168.0.0/16"),
+        cidr!("2001:db8::/33"),
+        cidr!("2001:db8:8000::/33"),
+        cidr!("10.0.1.0/24"),
+        cidr!("10.0.2.0/24"),
+        cidr!("10.0.3.0/24"),
+    ];
+
+    let mut builder = CidrSetBuilder::new();
+    for block in blocks {
+        builder.add(block);
+    }
+    builder.add_str("172.16.0.0/12").expect("valid cidr").add_str("192.168.1.0/24").expect("valid cidr");
+    assert!(builder.add_str("172.16.0.0/33").is_err());
+    let range = IpRange::new(net::IpAddr::from([10, 0, 4, 0]), net::IpAddr::from([10, 0, 7, 255])).expect("valid range");
+    let set = builder.add_range(range).build();
+
+    let mut expected = CidrSet::new();
+    let mut all = blocks.to_vec();
+    all.extend([cidr!("172.16.0.0/12"), cidr!("192.168.1.0/24"), cidr!("10.0.4.0/22")]);
+    for block in aggregate(&all) {
+        expected.insert(block);
+    }
+    assert_eq!(set, expected);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [cidr!("10.0.0.0/21"), cidr!("172.16.0.0/12"), cidr!("192.168.0.0/16"), cidr!("2001:db8::/32")]);
+
+    //Builder is reusable after build
+    assert_eq!(builder.build(), CidrSet::new());
+    assert_eq!(builder.add(cidr!("10.0.0.0/8")).build().len(), 1);
+}
+
+#[test]
+fn should_return_collapsed_ranges() {
+    let set = [cidr!("2001:db8::/32"), cidr!("10.0.0.128/25"), cidr!("10.0.0.0/25"), cidr!("10.0.0.64/26"), cidr!("192.168.1.0/24")].into_iter().collect::<CidrSet>();
+    let ranges = set.ranges();
+    assert_eq!(ranges.len(), 3);
+    assert_eq!(ranges.collect::<Vec<_>>(), [
+        (net::IpAddr::from([10, 0, 0, 0]), net::IpAddr::from([10, 0, 0, 255])),
+        (net::IpAddr::from([192, 168, 1, 0]), net::IpAddr::from([192, 168, 1, 255])),
+        (net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)), net::IpAddr::V6(net::Ipv6Addr::new(0x2001, 0xdb8, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff))),
+    ]);
+    assert_eq!(CidrSet::new().ranges().next(), None);
+}
+
+#[test]
+fn should_return_gaps_within_pool() {
+    let pool = cidr!("10.0.0.0/24");
+    let allocated = [cidr!("10.0.0.0/26"), cidr!("10.0.0.128/26")].into_iter().collect::<CidrSet>();
+    assert_eq!(allocated.gaps_within(&pool), [cidr!("10.0.0.64/26"), cidr!("10.0.0.192/26")]);
+
+    assert_eq!(CidrSet::new().gaps_within(&cidr!("10.0.0.7/24")), [pool]);
+    let covering = [cidr!("10.0.0.0/8"), cidr!("::/0")].into_iter().collect::<CidrSet>();
+    assert_eq!(covering.gaps_within(&pool), []);
+
+    //Blocks outside of the bound and of other family are ignored
+    let allocated = [cidr!("10.0.0.0/25"), cidr!("10.0.1.0/24"), cidr!("::/0"), cidr!("10.0.0.200/32")].into_iter().collect::<CidrSet>();
+    assert_eq!(allocated.gaps_within(&pool), [
+        cidr!("10.0.0.128/26"),
+        cidr!("10.0.0.192/29"),
+        cidr!("10.0.0.201/32"),
+        cidr!("10.0.0.202/31"),
+        cidr!("10.0.0.204/30"),
+        cidr!("10.0.0.208/28"),
+        cidr!("10.0.0.224/27"),
+    ]);
+
+    let allocated = [cidr!("::/2"), cidr!("c000::/2")].into_iter().collect::<CidrSet>();
+    assert_eq!(allocated.gaps_within(&cidr!("::/0")), [cidr!("4000::/2"), cidr!("8000::/2")]);
+}
+
+#[test]
+fn should_grow_until_disjoint() {
+    let avoid = [cidr!("10.0.0.0/24"), cidr!("10.0.2.0/24"), cidr!("10.128.0.0/9")].into_iter().collect::<CidrSet>();
+    //10.0.1.0/24 is the largest block around the address next to both 10.0.0.0/24 and 10.0.2.0/24
+    assert_eq!(cidr!("10.0.1.5/32").grow_until_disjoint(&avoid), Some(cidr!("10.0.1.0/24")));
+    assert_eq!(cidr!("10.0.3.1/32").grow_until_disjoint(&avoid), Some(cidr!("10.0.3.0/24")));
+    assert_eq!(cidr!("10.64.0.1/32").grow_until_disjoint(&avoid), Some(cidr!("10.64.0.0/10")));
+    assert_eq!(cidr!("192.168.0.1/32").grow_until_disjoint(&avoid), Some(cidr!("128.0.0.0/1")));
+    assert_eq!(cidr!("10.0.1.0/24").grow_until_disjoint(&avoid), Some(cidr!("10.0.1.0/24")));
+
+    //Already overlapping
+    assert_eq!(cidr!("10.0.0.5/32").grow_until_disjoint(&avoid), None);
+    assert_eq!(cidr!("10.0.0.0/23").grow_until_disjoint(&avoid), None);
+    assert_eq!(cidr!("10.0.0.0/16").grow_until_disjoint(&avoid), None);
+
+    //Nothing to avoid
+    assert_eq!(cidr!("2001:db8::1/128").grow_until_disjoint(&avoid), Some(cidr!("::/0")));
+    assert_eq!(cidr!("10.0.0.5/32").grow_until_disjoint(&CidrSet::new()), Some(cidr!("0.0.0.0/0")));
+}
+
+#[test]
+fn should_diff_policy_versions() {
+    let old = [cidr!("10.0.0.0/24"), cidr!("10.0.1.0/24"), cidr!("192.168.0.0/16")].into_iter().collect::<CidrSet>();
+    let new = [cidr!("10.0.0.0/23"), cidr!("192.168.0.0/17"), cidr!("2001:db8::/32")].into_iter().collect::<CidrSet>();
+    assert_eq!(old.diff(&new), (vec![cidr!("192.168.128.0/17")], vec![cidr!("2001:db8::/32")]));
+    assert_eq!(new.diff(&old), (vec![cidr!("2001:db8::/32")], vec![cidr!("192.168.128.0/17")]));
+    assert_eq!(old.diff(&old), (vec![], vec![]));
+    assert_eq!(old.diff(&CidrSet::new()), (vec![cidr!("10.0.0.0/23"), cidr!("192.168.0.0/16")], vec![]));
+
+    let (removed, added) = old.diff(&new);
+    assert_eq!(old.difference_count(&new), removed.len());
+    assert_eq!(new.difference_count(&old), added.len());
+}
+
+#[test]
+fn should_build_set_from_ranges() {
+    let ranges = [
+        IpRange::new(net::IpAddr::from([10, 0, 0, 0]), net::IpAddr::from([10, 0, 0, 99])).expect("valid range"),
+        IpRange::new(net::IpAddr::from([10, 0, 0, 100]), net::IpAddr::from([10, 0, 0, 255])).expect("valid range"),
+    ];
+    let set = CidrSet::from_ranges(ranges);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [cidr!("10.0.0.0/24")]);
+
+    let ranges = [
+        IpRange::new(net::IpAddr::from([10, 0, 0, 1]), net::IpAddr::from([10, 0, 0, 3])).expect("valid range"),
+        IpRange::from(cidr!("2001:db8::/32")),
+        IpRange::new(net::IpAddr::from([10, 0, 0, 2]), net::IpAddr::from([10, 0, 0, 4])).expect("valid range"),
+    ];
+    let set = CidrSet::from_ranges(ranges);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [cidr!("10.0.0.1/32"), cidr!("10.0.0.2/31"), cidr!("10.0.0.4/32"), cidr!("2001:db8::/32")]);
+    assert_eq!(CidrSet::from_ranges([]), CidrSet::new());
+}
+
+#[test]
+fn should_count_prefix_lengths() {
+    let set = [
+        cidr!("10.0.0.0/8"),
+        cidr!("10.0.0.0/24"),
+        cidr!("10.0.1.0/24"),
+        cidr!("192.168.0.0/16"),
+        cidr!("192.168.5.0/24"),
+        cidr!("2001:db8::/32"),
+        cidr!("2001:db8:1::/48"),
+        cidr!("2001:db8:1::/24"),
+    ].into_iter().collect::<CidrSet>();
+
+    let histogram = set.prefix_histogram();
+    assert_eq!(histogram.into_iter().collect::<Vec<_>>(), [(8, 1), (16, 1), (24, 4), (32, 1), (48, 1)]);
+    assert!(CidrSet::new().prefix_histogram().is_empty());
+}
+
+#[test]
+fn should_roundtrip_packed_bytes() {
+    //Deterministic pseudo-random blocks of both families
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let mut set = CidrSet::new();
+    for _ in 0..300 {
+        let value = next();
+        let cidr = match value % 2 {
+            0 => Cidr::new_v4(net::Ipv4Addr::from_bits(value as u32), (value >> 32) as u8 % 33),
+            _ => Cidr::new_v6(net::Ipv6Addr::from_bits((value as u128) << 64 | next() as u128), (value >> 32) as u8 % 129),
+        };
+        set.insert(cidr.expect("valid prefix"));
+    }
+    assert!(set.len() > 250);
+
+    let bytes = set.to_packed_bytes();
+    assert_eq!(&bytes[..5], b"CIDR\x01");
+    assert_eq!(bytes[5..13], (set.len() as u64).to_be_bytes());
+    assert_eq!(CidrSet::from_packed_bytes(&bytes), Ok(set));
+
+    let empty = CidrSet::new().to_packed_bytes();
+    assert_eq!(empty.len(), 13);
+    assert_eq!(CidrSet::from_packed_bytes(&empty), Ok(CidrSet::new()));
+}
+
+#[test]
+fn should_reject_invalid_packed_bytes() {
+    let set = [cidr!("10.0.0.0/8"), cidr!("2001:db8::/32")].into_iter().collect::<CidrSet>();
+    let bytes = set.to_packed_bytes();
+    assert_eq!(bytes.len(), 13 + 6 + 18);
+
+    assert_eq!(CidrSet::from_packed_bytes(&bytes[..12]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(CidrSet::from_packed_bytes(&bytes[..bytes.len() - 1]), Err(DecodeError::UnexpectedEnd));
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(CidrSet::from_packed_bytes(&trailing), Err(DecodeError::TrailingData));
+
+    let mut invalid = bytes.clone();
+    invalid[0] = b'X';
+    assert_eq!(CidrSet::from_packed_bytes(&invalid), Err(DecodeError::InvalidMagic));
+    let mut invalid = bytes.clone();
+    invalid[4] = 2;
+    assert_eq!(CidrSet::from_packed_bytes(&invalid), Err(DecodeError::UnsupportedVersion(2)));
+    let mut invalid = bytes.clone();
+    invalid[19] = 5;
+    assert_eq!(CidrSet::from_packed_bytes(&invalid), Err(DecodeError::InvalidEntry(19)));
+    let mut invalid = bytes.clone();
+    invalid[14] = 33;
+    assert_eq!(CidrSet::from_packed_bytes(&invalid), Err(DecodeError::InvalidEntry(13)));
+    let mut invalid = bytes;
+    invalid[12] = 3;
+    assert_eq!(CidrSet::from_packed_bytes(&invalid), Err(DecodeError::UnexpectedEnd));
+}
+
+#[test]
+fn should_get_exact_block() {
+    let set = [cidr!("10.0.0.0/16"), cidr!("10.0.0.0/26"), cidr!("::/0")].into_iter().collect::<CidrSet>();
+    assert_eq!(set.get_exact(&cidr!("10.0.0.0/24")), None);
+    assert_eq!(set.longest_match(net::IpAddr::from([10, 0, 0, 0])), Some(cidr!("10.0.0.0/26")));
+    assert_eq!(set.get_exact(&cidr!("10.0.0.0/16")), Some(cidr!("10.0.0.0/16")));
+    assert_eq!(set.get_exact(&cidr!("10.0.7.7/16")), Some(cidr!("10.0.0.0/16")));
+    assert_eq!(set.get_exact(&cidr!("10.0.0.0/8")), None);
+    assert_eq!(set.get_exact(&cidr!("10.0.0.0/32")), None);
+    assert_eq!(set.get_exact(&cidr!("::/0")), Some(cidr!("::/0")));
+    assert_eq!(set.get_exact(&cidr!("0.0.0.0/0")), None);
+    assert_eq!(CidrSet::new().get_exact(&cidr!("0.0.0.0/0")), None);
+}
+
+#[test]
+fn should_check_whole_block_coverage() {
+    let set = [cidr!("10.0.0.0/25"), cidr!("10.0.0.128/25"), cidr!("10.0.2.0/24"), cidr!("2001:db8::/32")].into_iter().collect::<CidrSet>();
+    assert!(set.contains_block(&cidr!("10.0.0.0/24")));
+    assert!(set.contains_block(&cidr!("10.0.0.64/26")));
+    assert!(set.contains_block(&cidr!("10.0.2.0/24")));
+    assert!(set.contains_block(&cidr!("2001:db8:1::/48")));
+    assert!(!set.contains_block(&cidr!("10.0.0.0/23")));
+    assert!(!set.contains_block(&cidr!("10.0.0.0/22")));
+    assert!(!set.contains_block(&cidr!("::/0")));
+    assert!(!CidrSet::new().contains_block(&cidr!("10.0.0.1/32")));
+}
+
+#[test]
+fn should_group_by_supernet() {
+    let set = [
+        cidr!("10.1.5.0/24"),
+        cidr!("10.1.7.0/24"),
+        cidr!("10.2.0.0/24"),
+        cidr!("10.1.0.0/16"),
+        cidr!("10.0.0.0/8"),
+        cidr!("192.168.1.0/24"),
+        cidr!("2001:db8:1::/48"),
+    ].into_iter().collect::<CidrSet>();
+
+    let groups = set.group_by_supernet(16);
+    assert_eq!(groups.into_iter().collect::<Vec<_>>(), [
+        (cidr!("10.1.0.0/16"), vec![cidr!("10.1.0.0/16"), cidr!("10.1.5.0/24"), cidr!("10.1.7.0/24")]),
+        (cidr!("10.2.0.0/16"), vec![cidr!("10.2.0.0/24")]),
+        (cidr!("192.168.0.0/16"), vec![cidr!("192.168.1.0/24")]),
+        (cidr!("2001::/16"), vec![cidr!("2001:db8:1::/48")]),
+    ]);
+
+    let groups = set.group_by_supernet(40);
+    assert_eq!(groups.into_iter().collect::<Vec<_>>(), [(cidr!("2001:db8::/40"), vec![cidr!("2001:db8:1::/48")])]);
+    assert!(CidrSet::new().group_by_supernet(0).is_empty());
+}
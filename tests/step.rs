@@ -0,0 +1,26 @@
+#![cfg(feature = "nightly")]
+
+use core::net;
+
+use ip_cidr::StepAddr;
+
+#[test]
+fn should_iterate_address_range() {
+    let start = StepAddr(net::Ipv4Addr::new(10, 0, 0, 254));
+    let end = StepAddr(net::Ipv4Addr::new(10, 0, 1, 1));
+    let addrs = (start..=end).map(|addr| addr.0.to_string()).collect::<Vec<_>>();
+    assert_eq!(addrs, ["10.0.0.254", "10.0.0.255", "10.0.1.0", "10.0.1.1"]);
+    assert_eq!((start..end).count(), 3);
+    assert_eq!((start..=end).next_back(), Some(end));
+    assert_eq!((end..start).next(), None);
+
+    //Does not overflow past the last address
+    let last = StepAddr(net::Ipv4Addr::BROADCAST);
+    assert_eq!((last..=last).collect::<Vec<_>>(), [last]);
+
+    let start = StepAddr(net::Ipv6Addr::UNSPECIFIED);
+    let end = StepAddr(ip_cidr::v6::LAST);
+    assert_eq!((start..=end).size_hint(), (usize::MAX, None));
+    assert_eq!((start..=end).nth(5), Some(StepAddr(net::Ipv6Addr::from_bits(5))));
+    assert_eq!((start..=end).nth_back(1), Some(StepAddr(net::Ipv6Addr::from_bits(u128::MAX - 1))));
+}
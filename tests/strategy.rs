@@ -0,0 +1,54 @@
+#![cfg(feature = "proptest")]
+
+use ip_cidr::strategy::{any_cidr, any_v4_cidr, any_v6_cidr, cidr_with_prefix};
+use ip_cidr::{parse_cidr_exact, Cidr};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn should_contain_network_addr(cidr in any_cidr()) {
+        prop_assert!(cidr.contains(cidr.network_addr()));
+        prop_assert!(cidr.contains(cidr.broadcast_addr()));
+        prop_assert_eq!(cidr.get(0), Some(cidr.network_addr()));
+    }
+
+    #[test]
+    fn should_roundtrip_through_parser(cidr in any_cidr()) {
+        let text = cidr.to_string();
+        prop_assert_eq!(parse_cidr_exact(&text), Ok(cidr));
+    }
+
+    #[test]
+    fn should_generate_valid_prefix(v4 in any_v4_cidr(), v6 in any_v6_cidr()) {
+        prop_assert!(v4.prefix() <= 32);
+        prop_assert!(v6.prefix() <= 128);
+    }
+
+    #[test]
+    fn should_generate_requested_prefix((prefix, cidr) in (0..=128u8).prop_flat_map(|prefix| (Just(prefix), cidr_with_prefix(prefix)))) {
+        prop_assert_eq!(cidr.prefix(), prefix);
+        if prefix > 32 {
+            prop_assert!(matches!(cidr, Cidr::V6(_)));
+        }
+    }
+}
+
+#[test]
+fn should_shrink_ipv6_only_prefix() {
+    use proptest::test_runner::{TestCaseError, TestError, TestRunner};
+
+    for prefix in [33, 40, 128] {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&cidr_with_prefix(prefix), |cidr| {
+            prop_assert_eq!(cidr.prefix(), prefix);
+            Err(TestCaseError::fail("always fails"))
+        });
+        match result {
+            Err(TestError::Fail(_, cidr)) => {
+                assert_eq!(cidr.prefix(), prefix);
+                assert_eq!(cidr.addr(), core::net::IpAddr::V6(core::net::Ipv6Addr::UNSPECIFIED));
+            },
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+}